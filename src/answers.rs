@@ -0,0 +1,80 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{ParseError, LoadError, error::{load, parse_number}, runner::Answers};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    // no answer was recorded for the part
+    Unchecked,
+}
+
+impl Verdict {
+    fn of(expected: &Option<String>, actual: &Option<String>) -> Verdict {
+        return match (expected, actual) {
+            (None, _) => Verdict::Unchecked,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            _ => Verdict::Fail,
+        };
+    }
+}
+
+pub struct ExpectedAnswers {
+    days: HashMap<u8, (Option<String>, Option<String>)>,
+}
+
+impl ExpectedAnswers {
+    pub fn get(&self, day: u8) -> Option<&(Option<String>, Option<String>)> {
+        return self.days.get(&day);
+    }
+
+    pub fn check(&self, day: u8, answers: &Answers) -> (Verdict, Verdict) {
+        return match self.days.get(&day) {
+            Some((part1, part2)) => (
+                Verdict::of(part1, &answers.part1),
+                Verdict::of(part2, &answers.part2),
+            ),
+            None => (Verdict::Unchecked, Verdict::Unchecked),
+        };
+    }
+}
+
+fn parse_answer(answer: &str) -> Option<String> {
+    return if answer == "-" { None } else { Some(answer.to_string()) };
+}
+
+// Every line holds a day and its answers, `-` stands for an answer which is
+// not known yet. Lines starting with `#` are comments.
+pub fn parse_expected_answers(input: &str) -> Result<ExpectedAnswers, ParseError> {
+    let mut days = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let split_line: Vec<&str> = line.split_whitespace().collect();
+
+        if split_line.len() != 3 {
+            return Err(ParseError::new(
+                line, line, "expected a day followed by the answers to both parts"
+            ).with_line(i + 1));
+        }
+
+        let day = parse_number::<u8>(line, split_line[0]).map_err(|err| err.with_line(i + 1))?;
+        let answers = (parse_answer(split_line[1]), parse_answer(split_line[2]));
+
+        if days.insert(day, answers).is_some() {
+            return Err(ParseError::new(
+                line, split_line[0], "the answers for this day were already given"
+            ).with_line(i + 1));
+        }
+    }
+
+    return Ok(ExpectedAnswers { days });
+}
+
+pub fn load_expected_answers(path: &Path) -> Result<ExpectedAnswers, LoadError> {
+    return load(path, parse_expected_answers);
+}
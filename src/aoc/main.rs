@@ -0,0 +1,270 @@
+use std::{env::args, io::ErrorKind, path::Path, process::exit, time::Duration};
+
+use aoc20::{
+    runner::{Day, Answers, Benchmark, Timings, DAYS, find_day},
+    answers::{ExpectedAnswers, Verdict, load_expected_answers},
+    error::load, input::{input_path, usage_error}, json, LoadError, ParseMode,
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [<input file>] [options]
+    aoc all <inputs directory> [options]
+    aoc list
+
+The input of `run` is read from the standard input when the file is `-` or missing.
+
+Options:
+    --lenient          skip malformed lines instead of rejecting the input
+    --format <format>  `text` (the default) or `json`, which prints an object per day
+    --bench <runs>     time parsing and both parts over the given number of runs
+    --check <file>     compare the answers with the ones recorded in the file and
+                       fail on any mismatch, the file has lines `<day> <part one> <part two>`
+                       where `-` marks an answer which is not recorded";
+
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Options {
+    mode: ParseMode,
+    format: Format,
+    bench: Option<usize>,
+    check: Option<ExpectedAnswers>,
+}
+
+fn format_part(answer: &Option<String>, error: &Option<String>) -> String {
+    return match error {
+        Some(error) => format!("error: {}", error),
+        None => format_answer(answer).to_string(),
+    };
+}
+
+fn format_answer(answer: &Option<String>) -> &str {
+    return match answer {
+        Some(value) => value,
+        None => "no answer",
+    };
+}
+
+fn format_verdict(verdict: Verdict, expected: &Option<String>) -> String {
+    return match verdict {
+        Verdict::Pass => String::from(" (PASS)"),
+        Verdict::Fail => format!(" (FAIL, expected {})", format_answer(expected)),
+        Verdict::Unchecked => String::from(" (not recorded)"),
+    };
+}
+
+fn json_verdict(verdict: Verdict) -> String {
+    return json::string(match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Fail => "fail",
+        Verdict::Unchecked => "unchecked",
+    });
+}
+
+// Returns whether none of the parts failed, and none of the answers failed the check.
+fn print_answers(day: &Day, answers: &Answers, options: &Options) -> bool {
+    let verdicts = options.check.as_ref().map(|expected| (
+        expected.check(day.number, answers),
+        expected.get(day.number).cloned().unwrap_or((None, None)),
+    ));
+
+    match options.format {
+        Format::Text => {
+            let (suffix1, suffix2) = match &verdicts {
+                Some(((verdict1, verdict2), (expected1, expected2))) => (
+                    format_verdict(*verdict1, expected1),
+                    format_verdict(*verdict2, expected2),
+                ),
+                None => (String::new(), String::new()),
+            };
+
+            println!("Day {:02}: {}", day.number, day.title);
+            println!("    part one: {}{}", format_part(&answers.part1, &answers.part1_error), suffix1);
+            println!("    part two: {}{}", format_part(&answers.part2, &answers.part2_error), suffix2);
+        }
+
+        Format::Json => {
+            let mut fields = vec![
+                ("day", day.number.to_string()),
+                ("part1", json::answer(&answers.part1)),
+                ("part2", json::answer(&answers.part2)),
+                ("elapsed_us", answers.elapsed.as_micros().to_string()),
+            ];
+
+            if let Some(error) = &answers.part1_error {
+                fields.push(("part1_error", json::string(error)));
+            }
+
+            if let Some(error) = &answers.part2_error {
+                fields.push(("part2_error", json::string(error)));
+            }
+
+            if let Some(((verdict1, verdict2), _)) = &verdicts {
+                fields.push(("part1_check", json_verdict(*verdict1)));
+                fields.push(("part2_check", json_verdict(*verdict2)));
+            }
+
+            println!("{}", json::object(&fields));
+        }
+    }
+
+    if options.mode == ParseMode::Lenient {
+        eprintln!("Day {:02}: skipped {} malformed lines", day.number, answers.skipped);
+    }
+
+    if answers.part1_error.is_some() || answers.part2_error.is_some() {
+        return false;
+    }
+
+    return match verdicts {
+        Some(((verdict1, verdict2), _)) => verdict1 != Verdict::Fail && verdict2 != Verdict::Fail,
+        None => true,
+    };
+}
+
+fn format_timings(timings: &Timings) -> String {
+    return format!(
+        "min {:?}, median {:?}, max {:?}", timings.min, timings.median, timings.max
+    );
+}
+
+fn json_timings(timings: &Timings) -> String {
+    let micros = |duration: Duration| format!("{:.3}", duration.as_secs_f64() * 1e6);
+
+    return json::object(&[
+        ("min_us", micros(timings.min)),
+        ("median_us", micros(timings.median)),
+        ("max_us", micros(timings.max)),
+    ]);
+}
+
+fn print_benchmark(day: &Day, benchmark: &Benchmark, options: &Options) {
+    match options.format {
+        Format::Text => {
+            println!("Day {:02}: {} ({} runs)", day.number, day.title, benchmark.runs);
+            println!("    parse:    {}", format_timings(&benchmark.parse));
+            println!("    part one: {}", format_timings(&benchmark.part1));
+            println!("    part two: {}", format_timings(&benchmark.part2));
+        }
+
+        Format::Json => {
+            println!("{}", json::object(&[
+                ("day", day.number.to_string()),
+                ("runs", benchmark.runs.to_string()),
+                ("parse", json_timings(&benchmark.parse)),
+                ("part1", json_timings(&benchmark.part1)),
+                ("part2", json_timings(&benchmark.part2)),
+            ]));
+        }
+    }
+}
+
+// Returns whether none of the parts failed, and none of the answers failed the check.
+fn run_day(day: &Day, path: &Path, options: &Options) -> Result<bool, LoadError> {
+    match options.bench {
+        Some(runs) => {
+            let benchmark = load(path, |input| day.bench(input, options.mode, runs))?;
+            print_benchmark(day, &benchmark, options);
+
+            return Ok(true);
+        }
+
+        None => {
+            let answers = load(path, |input| day.solve(input, options.mode))?;
+
+            return Ok(print_answers(day, &answers, options));
+        }
+    }
+}
+
+fn run_one(day_arg: &str, path: &Path, options: &Options) {
+    let day = match day_arg.parse::<u8>().ok().and_then(find_day) {
+        Some(day) => day,
+        None => usage_error(USAGE, &format!("Unknown day: {}", day_arg)),
+    };
+
+    if !run_day(day, path, options).unwrap_or_else(|err| err.exit()) {
+        exit(1);
+    }
+}
+
+fn run_all(inputs_dir: &Path, options: &Options) {
+    let mut failed = false;
+
+    for day in DAYS.iter() {
+        let path = inputs_dir.join(day.input_file_name());
+
+        match run_day(day, &path, options) {
+            Ok(passed) => failed |= !passed,
+            // only a missing input is skipped, one which cannot be read fails
+            Err(LoadError::Io { error, .. }) if error.kind() == ErrorKind::NotFound => {
+                if options.format == Format::Json {
+                    eprintln!("Day {:02}: skipped, {} not found", day.number, path.display());
+                } else {
+                    println!("Day {:02}: skipped, {} not found", day.number, path.display());
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        exit(1);
+    }
+}
+
+fn list() {
+    for day in DAYS.iter() {
+        println!("{:2}  {}", day.number, day.title);
+    }
+}
+
+fn main() {
+    let mut options = Options { mode: ParseMode::Strict, format: Format::Text, bench: None, check: None };
+    let mut positional: Vec<String> = Vec::new();
+    let mut args = args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lenient" => options.mode = ParseMode::Lenient,
+            "--format" => options.format = match args.next().as_deref() {
+                Some("text") => Format::Text,
+                Some("json") => Format::Json,
+                Some(other) => usage_error(USAGE, &format!("Unknown format: {}", other)),
+                None => usage_error(USAGE, "Expected a format after --format"),
+            },
+            "--bench" => options.bench = match args.next().map(|runs| runs.parse::<usize>()) {
+                Some(Ok(runs)) if runs > 0 => Some(runs),
+                Some(_) => usage_error(USAGE, "The number of runs has to be a positive number"),
+                None => usage_error(USAGE, "Expected a number of runs after --bench"),
+            },
+            "--check" => options.check = match args.next() {
+                Some(path) => Some(load_expected_answers(Path::new(&path)).unwrap_or_else(|err| err.exit())),
+                None => usage_error(USAGE, "Expected a file with answers after --check"),
+            },
+            flag if flag.starts_with("--") => usage_error(USAGE, &format!("Unknown option: {}", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if options.bench.is_some() && options.check.is_some() {
+        usage_error(USAGE, "--bench and --check cannot be combined");
+    }
+
+    match positional.first().map(String::as_str) {
+        Some("run") if (2..=3).contains(&positional.len()) => run_one(
+            &positional[1], input_path(positional.get(2).map(String::as_str)), &options
+        ),
+        Some("all") if positional.len() == 2 => run_all(Path::new(&positional[1]), &options),
+        Some("list") if positional.len() == 1 => list(),
+        Some(_) => usage_error(USAGE, "Unexpected arguments!"),
+        None => usage_error(USAGE, "Expected a subcommand!"),
+    }
+}
@@ -0,0 +1,24 @@
+// Just enough CSV writing for the exported reports, quoting the fields the
+// way spreadsheets expect.
+
+pub fn field(value: &str) -> String {
+    // spreadsheets run a cell starting like this as a formula, which the
+    // exported data is never meant to be
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if !value.contains([',', '"', '\n', '\r']) {
+        return value;
+    }
+
+    return format!("\"{}\"", value.replace('"', "\"\""));
+}
+
+pub fn record(fields: &[String]) -> String {
+    let fields: Vec<String> = fields.iter().map(|value| field(value)).collect();
+
+    return fields.join(",");
+}
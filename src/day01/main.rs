@@ -1,73 +1,141 @@
-use std::{fs::read_to_string, path::Path, env::args};
-
-
-fn read_numbers(input_path: &Path) -> Vec<u32> {
-    return read_to_string(input_path)
-        .expect("Input file Not Found.")
-        .lines()
-        .map(String::from)
-        .filter(|s| !s.is_empty())
-        .map(|bytes| { String::from(bytes).parse::<u32>()
-        .expect("Expected a number!") })
-        .collect();
+use std::{env::args, process::exit, str::FromStr};
+
+use aoc20::{
+    day01::{
+        read_numbers, find_summing_to, combinations_summing_to, checked_product,
+        subset_sum::{count_subsets_summing_to, smallest_subset_summing_to, SubsetSumError},
+    },
+    input::{input_path, usage_error},
+};
+
+const USAGE: &str = "\
+Usage: day01 [<input file>] [options]
+
+Options:
+    --target <sum>  the sum to look for, 2020 by default
+    --k <count>     how many numbers to add up, both 2 and 3 by default
+    --all           list every combination instead of the first one
+    --subsets       count the subsets of any size instead and show the smallest one";
+
+fn parse_value<T: FromStr>(value: Option<String>, option: &str) -> T {
+    return match value.map(|value| value.parse::<T>()) {
+        Some(Ok(value)) => value,
+        Some(Err(_)) => usage_error(USAGE, &format!("The value of {} has to be a number", option)),
+        None => usage_error(USAGE, &format!("Expected a number after {}", option)),
+    };
 }
 
-fn find_summing_to(nums: &[u32], count: u32, total: u32) -> Option<Vec<u32>> {
-    if count == 0 && total == 0 {
-        return Some(Vec::new());
-    }
-
-    if count == 0 {
-        return None;
-    }
+fn list_numbers(numbers: &[i64]) -> String {
+    return match numbers.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!(
+            "{} and {}", rest.iter().map(i64::to_string).collect::<Vec<_>>().join(", "), last
+        ),
+        None => String::new(),
+    };
+}
 
-    for (i, num) in nums.iter().filter(|n| n <= &&total).enumerate() {
-        match find_summing_to(&nums[i+1..], count - 1, total - num) {
-            None => {
-                continue;
-            }
+// Returns whether the product could be worked out.
+fn print_found(numbers: &[i64]) -> bool {
+    match checked_product(numbers) {
+        Ok(product) => {
+            println!("Found numbers {}, their product={}", list_numbers(numbers), product);
+            return true;
+        }
 
-            Some(mut v) => {
-                v.push(num.clone());
-                return Some(v);
-            }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return false;
         }
     }
-
-    return None;
 }
 
+fn find_subsets(nums: &[i64], target: i64) -> Result<(u128, Option<Vec<i64>>), SubsetSumError> {
+    return Ok((count_subsets_summing_to(nums, target)?, smallest_subset_summing_to(nums, target)?));
+}
 
 fn main() {
-    let args: Vec<String> = args().collect();
+    let mut target: i64 = 2020;
+    let mut k: Option<u32> = None;
+    let mut all = false;
+    let mut subsets = false;
+    let mut path: Option<String> = None;
+    let mut args = args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--target" => target = parse_value(args.next(), "--target"),
+            "--k" => k = match parse_value::<u32>(args.next(), "--k") {
+                0 => usage_error(USAGE, "At least one number has to be added up"),
+                count => Some(count),
+            },
+            "--all" => all = true,
+            "--subsets" => subsets = true,
+            flag if flag.starts_with("--") => usage_error(USAGE, &format!("Unknown option: {}", flag)),
+            _ if path.is_none() => path = Some(arg),
+            _ => usage_error(USAGE, "Unexpected arguments!"),
+        }
+    }
 
-    if args.len() < 2 {
-        panic!("Expected an argument!");
+    if subsets && (all || k.is_some()) {
+        usage_error(USAGE, "--subsets cannot be combined with --k or --all");
     }
 
-    let path_to_input = Path::new(&args[1]);
+    let path_to_input = input_path(path.as_deref());
 
-    let nums = read_numbers(path_to_input);
+    let nums: Vec<i64> = read_numbers(path_to_input).unwrap_or_else(|err| err.exit());
+    let mut failed = false;
 
-    match find_summing_to(&nums, 2, 2020) {
-        None => {
-            println!("Did not find any such numbers :-(");
-        }
+    if subsets {
+        match find_subsets(&nums, target) {
+            Ok((count, smallest)) => {
+                println!("There are {} subsets summing to {}", count, target);
 
-        Some(v) => {
-            println!("Found numbers {} and {}, their product={}",
-                     v[0], v[1], v[0] * v[1]);
+                match smallest {
+                    None => println!("Did not find any such numbers :-("),
+                    Some(v) => println!("The smallest one has {} numbers: {}", v.len(), list_numbers(&v)),
+                }
+            }
+
+            Err(err) => {
+                eprintln!("error: {}", err);
+                exit(1);
+            }
         }
+
+        return;
     }
 
-    match find_summing_to(&nums, 3, 2020) {
-        None => {
-            println!("Did not find any such numbers :-(");
+    let counts = match k {
+        Some(count) => vec![count],
+        None => vec![2, 3],
+    };
+
+    for count in counts {
+        if all {
+            let mut found = 0;
+
+            for v in combinations_summing_to(&nums, count, target) {
+                failed |= !print_found(&v);
+                found += 1;
+            }
+
+            println!("Found {} combinations of {} numbers summing to {}", found, count, target);
+            continue;
         }
 
-        Some(v) => {
-            println!("Found numbers {}, {} and {}, their product={}",
-                     v[0], v[1], v[2], v[0] * v[1] * v[2]);
+        match find_summing_to(&nums, count, target) {
+            None => {
+                println!("Did not find any such numbers :-(");
+            }
+
+            Some(v) => {
+                failed |= !print_found(&v);
+            }
         }
     }
+
+    if failed {
+        exit(1);
+    }
 }
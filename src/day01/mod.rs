@@ -0,0 +1,251 @@
+pub mod subset_sum;
+
+use std::{any::type_name, cmp::Ordering, fmt::{self, Display}, path::Path, str::FromStr};
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::{load, parse_lines, parse_number}};
+
+// The integer types the expenses can be read as. Sums are worked out in i128,
+// which none of them can overflow, only the products stay in the type itself.
+pub trait Amount: Copy + Ord + Display + FromStr + Into<i128> {
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_amount {
+    ($($t:ty),*) => {
+        $(
+            impl Amount for $t {
+                const ONE: $t = 1;
+
+                fn checked_mul(self, other: $t) -> Option<$t> {
+                    return <$t>::checked_mul(self, other);
+                }
+            }
+        )*
+    };
+}
+
+impl_amount!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductOverflow<T> {
+    pub numbers: Vec<T>,
+}
+
+impl<T: Amount> fmt::Display for ProductOverflow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numbers: Vec<String> = self.numbers.iter().map(T::to_string).collect();
+
+        return write!(f, "the product of {} does not fit into {}", numbers.join(", "), type_name::<T>());
+    }
+}
+
+impl<T: Amount + fmt::Debug> std::error::Error for ProductOverflow<T> {}
+
+pub fn checked_product<T: Amount>(numbers: &[T]) -> Result<T, ProductOverflow<T>> {
+    return numbers
+        .iter()
+        .try_fold(T::ONE, |product, num| product.checked_mul(*num))
+        .ok_or_else(|| ProductOverflow { numbers: numbers.to_vec() });
+}
+
+pub fn parse_numbers<T: Amount>(input: &str) -> Result<Vec<T>, ParseError> {
+    return parse_lines(input, |line| parse_number(line, line));
+}
+
+pub fn read_numbers<T: Amount>(input_path: &Path) -> Result<Vec<T>, LoadError> {
+    return load(input_path, parse_numbers);
+}
+
+// `nums` must be sorted. The innermost level walks the slice from both ends,
+// the outer ones try every distinct number as the smallest of the rest.
+fn find_in_sorted<T: Amount>(nums: &[T], count: usize, total: i128) -> Option<Vec<T>> {
+    match count {
+        0 => return if total == 0 { Some(Vec::new()) } else { None },
+
+        1 => {
+            let position = nums.partition_point(|num| (*num).into() < total);
+
+            return nums.get(position).filter(|num| (**num).into() == total).map(|num| vec![*num]);
+        }
+
+        2 => {
+            let (mut low, mut high) = (0, nums.len().checked_sub(1)?);
+
+            while low < high {
+                match (nums[low].into() + nums[high].into()).cmp(&total) {
+                    Ordering::Equal => return Some(vec![nums[low], nums[high]]),
+                    Ordering::Less => low += 1,
+                    Ordering::Greater => high -= 1,
+                }
+            }
+
+            return None;
+        }
+
+        _ => {
+            for (i, num) in nums.iter().enumerate() {
+                let value: i128 = (*num).into();
+
+                // the rest are at least as big, so no later number can work either
+                if value * count as i128 > total {
+                    break;
+                }
+
+                if i > 0 && nums[i - 1] == *num {
+                    continue;
+                }
+
+                if let Some(mut rest) = find_in_sorted(&nums[i + 1..], count - 1, total - value) {
+                    rest.insert(0, *num);
+                    return Some(rest);
+                }
+            }
+
+            return None;
+        }
+    }
+}
+
+// Finds `count` numbers at different positions of `nums` which add up to
+// `total`, in ascending order.
+pub fn find_summing_to<T: Amount>(nums: &[T], count: u32, total: T) -> Option<Vec<T>> {
+    let mut sorted = nums.to_vec();
+
+    sorted.sort_unstable();
+
+    return find_in_sorted(&sorted, count as usize, total.into());
+}
+
+// Every distinct combination of `count` numbers from `nums` which add up to
+// `total`, each in ascending order and the combinations in lexicographic
+// order. Equal numbers can be picked as many times as they appear.
+pub struct SumCombinations<T> {
+    nums: Vec<T>,
+    count: usize,
+    total: i128,
+    // positions in `nums` of the numbers picked so far
+    picked: Vec<usize>,
+    sum: i128,
+    // the next position to try for the following pick
+    candidate: usize,
+    done: bool,
+}
+
+impl<T: Amount> SumCombinations<T> {
+    // Drops the last pick and moves past all the numbers equal to it,
+    // so that no combination comes up twice.
+    fn backtrack(&mut self) {
+        match self.picked.pop() {
+            None => self.done = true,
+
+            Some(last) => {
+                self.sum -= self.nums[last].into();
+                self.candidate = last + 1;
+
+                while self.candidate < self.nums.len() && self.nums[self.candidate] == self.nums[last] {
+                    self.candidate += 1;
+                }
+            }
+        }
+    }
+
+    fn pick(&mut self, position: usize) {
+        self.picked.push(position);
+        self.sum += self.nums[position].into();
+        self.candidate = position + 1;
+    }
+}
+
+impl<T: Amount> Iterator for SumCombinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        while !self.done {
+            let remaining = (self.count - self.picked.len()) as i128;
+            let missing = self.total - self.sum;
+            let rest = &self.nums[self.candidate..];
+
+            if remaining == 0 {
+                let found = self.picked.iter().map(|i| self.nums[*i]).collect();
+                let complete = missing == 0;
+
+                self.backtrack();
+
+                if complete {
+                    return Some(found);
+                }
+            } else if remaining == 1 {
+                // the last number is known, so it only has to be looked up
+                let position = self.candidate + rest.partition_point(|num| (*num).into() < missing);
+
+                if position < self.nums.len() && self.nums[position].into() == missing {
+                    self.pick(position);
+                } else {
+                    self.backtrack();
+                }
+            } else if !rest.is_empty() && rest[0].into() * remaining <= missing {
+                self.pick(self.candidate);
+            } else {
+                // the numbers are sorted, so none of the later ones fit either
+                self.backtrack();
+            }
+        }
+
+        return None;
+    }
+}
+
+pub fn combinations_summing_to<T: Amount>(nums: &[T], count: u32, total: T) -> SumCombinations<T> {
+    let mut sorted = nums.to_vec();
+
+    sorted.sort_unstable();
+
+    return SumCombinations {
+        nums: sorted,
+        count: count as usize,
+        total: total.into(),
+        picked: Vec::new(),
+        sum: 0,
+        candidate: 0,
+        done: false,
+    };
+}
+
+pub struct Day01;
+
+fn product_summing_to(nums: &[i64], count: u32) -> Result<Option<i64>, String> {
+    return match find_summing_to(nums, count, 2020) {
+        Some(found) => checked_product(&found).map(Some).map_err(|overflow| overflow.to_string()),
+        None => Ok(None),
+    };
+}
+
+// A product of the found entries which overflows is an error, rather than
+// no answer.
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseErrors> {
+        return Ok(parse_numbers(input)?);
+    }
+
+    fn part_one(nums: &Vec<i64>) -> Option<i64> {
+        return Self::try_part_one(nums).ok().flatten();
+    }
+
+    fn part_two(nums: &Vec<i64>) -> Option<i64> {
+        return Self::try_part_two(nums).ok().flatten();
+    }
+
+    fn try_part_one(nums: &Vec<i64>) -> Result<Option<i64>, String> {
+        return product_summing_to(nums, 2);
+    }
+
+    fn try_part_two(nums: &Vec<i64>) -> Result<Option<i64>, String> {
+        return product_summing_to(nums, 3);
+    }
+}
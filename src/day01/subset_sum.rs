@@ -0,0 +1,133 @@
+// Subsets of any size, counted by the positions of the numbers, so equal
+// numbers at different positions make different subsets. Both tables have
+// an entry for every sum a subset can reach on its way to the target.
+
+use std::fmt;
+
+use super::Amount;
+
+// The limit on the entries of a table, big amounts would need too much memory.
+pub const MAX_TABLE_SIZE: i128 = 1 << 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubsetSumError {
+    TableTooLarge { entries: i128 },
+    CountOverflow,
+}
+
+impl fmt::Display for SubsetSumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            SubsetSumError::TableTooLarge { entries } => write!(
+                f, "the amounts need a table of {} entries, at most {} are allowed", entries, MAX_TABLE_SIZE
+            ),
+            SubsetSumError::CountOverflow => write!(f, "there are more subsets than fit into an u128"),
+        };
+    }
+}
+
+impl std::error::Error for SubsetSumError {}
+
+// The lowest and highest sums the tables have to cover, None when the target
+// is out of reach. With only non-negative numbers nothing above the target
+// matters, negative numbers can bring a bigger sum back down.
+fn sum_range<T: Amount>(nums: &[T], total: i128, tables: usize) -> Result<Option<(i128, i128)>, SubsetSumError> {
+    let values = nums.iter().map(|num| (*num).into());
+    let low: i128 = values.clone().filter(|value| *value < 0).sum();
+    let high: i128 = if low < 0 { values.filter(|value| *value > 0).sum() } else { total };
+
+    if total < low || total > high {
+        return Ok(None);
+    }
+
+    let entries = (high - low + 1) * tables as i128;
+
+    if entries > MAX_TABLE_SIZE {
+        return Err(SubsetSumError::TableTooLarge { entries });
+    }
+
+    return Ok(Some((low, high)));
+}
+
+// The sums which can have `value` added to them, in an order which uses
+// every number at most once - a sum is always updated before it is read.
+fn updated_sums(low: i128, high: i128, value: i128) -> Box<dyn Iterator<Item = i128>> {
+    if value >= 0 {
+        return Box::new((low + value..=high).rev());
+    }
+
+    return Box::new(low..=high + value);
+}
+
+pub fn count_subsets_summing_to<T: Amount>(nums: &[T], total: T) -> Result<u128, SubsetSumError> {
+    let total: i128 = total.into();
+    let (low, high) = match sum_range(nums, total, 1)? {
+        Some(range) => range,
+        None => return Ok(0),
+    };
+    let index = |sum: i128| (sum - low) as usize;
+    let mut counts: Vec<u128> = vec![0; index(high) + 1];
+
+    counts[index(0)] = 1;
+
+    for value in nums.iter().map(|num| (*num).into()) {
+        for sum in updated_sums(low, high, value) {
+            counts[index(sum)] = counts[index(sum)]
+                .checked_add(counts[index(sum - value)])
+                .ok_or(SubsetSumError::CountOverflow)?;
+        }
+    }
+
+    return Ok(counts[index(total)]);
+}
+
+// One of the subsets with the fewest numbers, in the order of `nums`.
+pub fn smallest_subset_summing_to<T: Amount>(nums: &[T], total: T) -> Result<Option<Vec<T>>, SubsetSumError> {
+    let total: i128 = total.into();
+    let (low, high) = match sum_range(nums, total, nums.len() + 1)? {
+        Some(range) => range,
+        None => return Ok(None),
+    };
+    let index = |sum: i128| (sum - low) as usize;
+    let mut sizes: Vec<Option<usize>> = vec![None; index(high) + 1];
+    // taken[i][sum] is set when the i-th number improved the subset for `sum`
+    let mut taken: Vec<Vec<bool>> = Vec::with_capacity(nums.len());
+
+    sizes[index(0)] = Some(0);
+
+    for value in nums.iter().map(|num| (*num).into()) {
+        let mut improved = vec![false; index(high) + 1];
+
+        for sum in updated_sums(low, high, value) {
+            let with_num = sizes[index(sum - value)].map(|size| size + 1);
+            let current = sizes[index(sum)];
+
+            if with_num.is_some() && (current.is_none() || with_num < current) {
+                sizes[index(sum)] = with_num;
+                improved[index(sum)] = true;
+            }
+        }
+
+        taken.push(improved);
+    }
+
+    if sizes[index(total)].is_none() {
+        return Ok(None);
+    }
+
+    // The last number which improved a sum is part of its final subset, the
+    // rest of that subset only uses the numbers before it.
+    let mut subset = Vec::new();
+    let mut sum = total;
+
+    for (i, num) in nums.iter().enumerate().rev() {
+        if taken[i][index(sum)] {
+            subset.push(*num);
+            sum -= (*num).into();
+        }
+    }
+
+    subset.reverse();
+
+    return Ok(Some(subset));
+}
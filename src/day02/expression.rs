@@ -0,0 +1,169 @@
+// Policies composed from the named ones with AND, OR, NOT and parentheses,
+// like `sled AND NOT toboggan`. NOT binds tightest and AND binds tighter
+// than OR, the keywords can be written in any case.
+
+use super::{Rule, policy::{PasswordPolicy, POLICIES, find_policy}};
+use crate::ParseError;
+
+enum Node {
+    Policy(&'static dyn PasswordPolicy),
+    Not(Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        return match self {
+            Node::Policy(policy) => policy.accepts(rule, password),
+            Node::Not(node) => !node.accepts(rule, password),
+            Node::And(left, right) => left.accepts(rule, password) && right.accepts(rule, password),
+            Node::Or(left, right) => left.accepts(rule, password) || right.accepts(rule, password),
+        };
+    }
+}
+
+pub struct PolicyExpression {
+    source: String,
+    root: Node,
+}
+
+impl PasswordPolicy for PolicyExpression {
+    fn name(&self) -> &str {
+        return &self.source;
+    }
+
+    fn description(&self) -> &str {
+        return "a policy expression";
+    }
+
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        return self.root.accepts(rule, password);
+    }
+}
+
+// The tokens are slices of the source, so that errors can point at them.
+fn tokenize(source: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (i, ch) in source.char_indices() {
+        if ch.is_whitespace() || ch == '(' || ch == ')' {
+            if let Some(word_start) = start.take() {
+                tokens.push(&source[word_start..i]);
+            }
+
+            if !ch.is_whitespace() {
+                tokens.push(&source[i..i + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    if let Some(word_start) = start {
+        tokens.push(&source[word_start..]);
+    }
+
+    return tokens;
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        return self.tokens.get(self.position).copied();
+    }
+
+    fn next_is_keyword(&self, keyword: &str) -> bool {
+        return self.peek().is_some_and(|token| token.eq_ignore_ascii_case(keyword));
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        // past the last token the error points right after the expression
+        let text = self.peek().unwrap_or(&self.source[self.source.len()..]);
+
+        return ParseError::new(self.source, text, message).with_line(1);
+    }
+
+    fn parse_or(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.parse_and()?;
+
+        while self.next_is_keyword("or") {
+            self.position += 1;
+            node = Node::Or(Box::new(node), Box::new(self.parse_and()?));
+        }
+
+        return Ok(node);
+    }
+
+    fn parse_and(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.parse_not()?;
+
+        while self.next_is_keyword("and") {
+            self.position += 1;
+            node = Node::And(Box::new(node), Box::new(self.parse_not()?));
+        }
+
+        return Ok(node);
+    }
+
+    fn parse_not(&mut self) -> Result<Node, ParseError> {
+        if self.next_is_keyword("not") {
+            self.position += 1;
+            return Ok(Node::Not(Box::new(self.parse_not()?)));
+        }
+
+        return self.parse_atom();
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, ParseError> {
+        let token = match self.peek() {
+            Some(token) => token,
+            None => return Err(self.error("expected a policy, NOT or '('")),
+        };
+
+        if token == "(" {
+            self.position += 1;
+            let node = self.parse_or()?;
+
+            if self.peek() != Some(")") {
+                return Err(self.error("expected ')'"));
+            }
+
+            self.position += 1;
+            return Ok(node);
+        }
+
+        if token == ")" || ["and", "or"].iter().any(|keyword| token.eq_ignore_ascii_case(keyword)) {
+            return Err(self.error("expected a policy, NOT or '('"));
+        }
+
+        return match find_policy(token) {
+            Some(policy) => {
+                self.position += 1;
+                Ok(Node::Policy(policy))
+            }
+
+            None => {
+                let names: Vec<&str> = POLICIES.iter().map(|policy| policy.name()).collect();
+                Err(self.error(&format!("unknown policy, expected one of {}", names.join(", "))))
+            }
+        };
+    }
+}
+
+pub fn parse_policy_expression(source: &str) -> Result<PolicyExpression, ParseError> {
+    let mut parser = Parser { source, tokens: tokenize(source), position: 0 };
+    let root = parser.parse_or()?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error("expected AND, OR or the end of the expression"));
+    }
+
+    return Ok(PolicyExpression { source: source.trim().to_string(), root });
+}
@@ -1,139 +1,119 @@
-use std::{fs::read_to_string, path::Path, env::args};
-
-struct Rule {
-    letter: char,
-    low: u8,
-    high: u8,
+use std::{env::args, path::Path, process::exit};
+
+use aoc20::{
+    day02::{
+        count_valid_in_file,
+        policy::{PasswordPolicy, POLICIES, SledPolicy, TobogganPolicy},
+        expression::{PolicyExpression, parse_policy_expression},
+        report::{read_report, csv_header, csv_row},
+    },
+    input::{input_path, usage_error, report_skipped}, ParseMode,
+};
+
+const USAGE: &str = "\
+Usage: day02 [<input file>] [options]
+
+Options:
+    --lenient        skip malformed lines instead of rejecting the input
+    --policy <name>  count the passwords valid under the policy, can be given
+                     more than once, `all` stands for every known policy
+    --policy <expr>  the same for policies combined with AND, OR, NOT and
+                     parentheses, like `sled AND NOT toboggan`
+    --report <text|csv>
+                     list every line with its rule, the letter count and
+                     positions, and the verdict of each policy (sled and
+                     toboggan unless --policy is given)
+    --list-policies  print the known policies";
+
+#[derive(PartialEq)]
+enum ReportFormat {
+    Text,
+    Csv,
 }
 
-impl Rule {
-    fn satisfied_by_sled(&self, word: &str) -> bool {
-        let count: u8 = u8::try_from(
-            word.chars().filter(|ch| ch.clone() == self.letter).count()
-        ).expect("Password was to large");
-
-        return count >= self.low && count <= self.high;
-    }
-
-    fn satisfied_by_toboggan(&self, word: &str) -> bool {
-        let first_matches = word.chars().nth(usize::from(self.low) - 1) == Some(self.letter);
-        let second_matches = word.chars().nth(usize::from(self.high) - 1) == Some(self.letter);
-
-        return first_matches != second_matches;
+fn list_policies() {
+    for policy in POLICIES.iter() {
+        println!("{:10}  {}", policy.name(), policy.description());
     }
 }
 
-fn parse_low_high(range_str: &str) -> Option<(u8, u8)> {
-    let split_str: Vec<&str> = range_str.split('-').collect();
-
-    if split_str.len() != 2 {
-        return None;
-    }
+fn parse_policy(source: &str) -> PolicyExpression {
+    return parse_policy_expression(source).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        exit(2);
+    });
+}
 
-    let low = split_str[0].parse::<u8>();
-    let high = split_str[1].parse::<u8>();
+fn print_report(path: &Path, mode: ParseMode, policies: &[&dyn PasswordPolicy], format: ReportFormat) {
+    let (reports, skipped) = read_report(path, mode, policies).unwrap_or_else(|err| err.exit());
 
-    match (low, high) {
-        (Err(_), _) => {
-            return None;
-        }
+    report_skipped(mode, skipped);
 
-        (_, Err(_)) => {
-            return None;
-        }
+    if format == ReportFormat::Csv {
+        println!("{}", csv_header(policies));
+    }
 
-        (Ok(low_value), Ok(high_value)) => {
-            return Some((low_value, high_value));
+    for report in reports.iter() {
+        match format {
+            ReportFormat::Text => println!("{}", report),
+            ReportFormat::Csv => println!("{}", csv_row(report)),
         }
-
     }
 }
 
-fn parse_rule(rule_str: &str) -> Option<Rule> {
-    let split_str: Vec<&str> = rule_str.split(' ').collect();
-
-    if split_str.len() != 2 || split_str[1].len() != 1 {
-        return None;
-    }
-
-    let low_high = parse_low_high(split_str[0]);
-    let letter = split_str[1].chars().nth(0);
-
-    match (low_high, letter) {
-        (_, None) => {
-            return None;
-        }
-
-        (None, _) => {
-            return None;
-        }
-
-        (Some((low, high)), Some(l)) => {
-            return Some(Rule {
-                letter: l,
-                low,
-                high
-            });
+fn main() {
+    let mut mode = ParseMode::Strict;
+    let mut report: Option<ReportFormat> = None;
+    let mut policies: Vec<PolicyExpression> = Vec::new();
+    let mut path: Option<String> = None;
+    let mut args = args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lenient" => mode = ParseMode::Lenient,
+            "--policy" => match args.next().as_deref() {
+                Some("all") => policies.extend(POLICIES.iter().map(|policy| parse_policy(policy.name()))),
+                Some(source) => policies.push(parse_policy(source)),
+                None => usage_error(USAGE, "Expected a policy after --policy"),
+            },
+            "--report" => match args.next().as_deref() {
+                Some("text") => report = Some(ReportFormat::Text),
+                Some("csv") => report = Some(ReportFormat::Csv),
+                Some(format) => usage_error(USAGE, &format!("Unknown report format: {}", format)),
+                None => usage_error(USAGE, "Expected a format after --report"),
+            },
+            "--list-policies" => return list_policies(),
+            flag if flag.starts_with("--") => usage_error(USAGE, &format!("Unknown option: {}", flag)),
+            _ if path.is_none() => path = Some(arg),
+            _ => usage_error(USAGE, "Unexpected arguments!"),
         }
     }
-}
 
-fn parse_line(line: &str) -> Option<(Rule, String)> {
-    let split_line: Vec<&str> = line.split(": ").collect();
+    let path_to_input = input_path(path.as_deref());
+    let defaults = policies.is_empty();
+    let mut selected: Vec<&dyn PasswordPolicy> = policies.iter().map(|policy| policy as &dyn PasswordPolicy).collect();
 
-    if split_line.len() != 2 {
-        return None;
+    if defaults {
+        selected = vec![&SledPolicy, &TobogganPolicy];
     }
 
-    let rule = parse_rule(split_line[0]);
-    let word = split_line[1];
-
-    match rule {
-        Some(r) => {
-            return Some((r, word.to_string()));
-        }
-
-        None => {
-            return None;
-        }
+    if let Some(format) = report {
+        return print_report(path_to_input, mode, &selected, format);
     }
-}
 
-fn read_lines(path: &Path) -> Vec<(Rule, String)> {
-    return read_to_string(path)
-        .expect("Input file Not Found")
-        .lines()
-        .map(parse_line)
-        .filter(|line| line.is_some())
-        .map(|line| line.unwrap())
-        .collect();
-}
-
-fn count_sled_valid(rules_with_words: &Vec<(Rule, String)>) -> usize {
-    return rules_with_words
-        .iter()
-        .filter(|(r, word)| r.satisfied_by_sled(word))
-        .count();
-}
-
-fn count_toboggan_valid(rules_with_words: &Vec<(Rule, String)>) -> usize {
-    return rules_with_words
-        .iter()
-        .filter(|(r, word)| r.satisfied_by_toboggan(word))
-        .count();
-}
+    let (counts, skipped) = count_valid_in_file(path_to_input, mode, &selected).unwrap_or_else(|err| err.exit());
 
-fn main() {
-    let args: Vec<String> = args().collect();
+    report_skipped(mode, skipped);
 
-    if args.len() < 2 {
-        panic!("Expected an argument!");
+    if defaults {
+        println!("Number of valid passwords (sled store): {}", counts[0].1);
+        println!("Number of valid passwords (toboggan): {}", counts[1].1);
+        return;
     }
 
-    let path_to_input = Path::new(&args[1]);
+    let width = counts.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
 
-    let parsed_lines = read_lines(path_to_input);
-
-    println!("Number of valid passwords (sled store): {}", count_sled_valid(&parsed_lines));
-    println!("Number of valid passwords (toboggan): {}", count_toboggan_valid(&parsed_lines));
+    for (name, count) in counts {
+        println!("{:width$}  {}", name, count, width = width);
+    }
 }
@@ -0,0 +1,138 @@
+pub mod policy;
+pub mod expression;
+pub mod report;
+
+use std::{io::BufRead, path::Path};
+
+use crate::{
+    Solution, ParseError, ParseErrors, ParseMode, LoadError,
+    error::{load, parse_lines_checked, parse_number, stream_lines_checked},
+    input::open_input,
+};
+use policy::{PasswordPolicy, PolicyCounts, count_valid, SledPolicy, TobogganPolicy};
+
+pub struct Rule {
+    letter: char,
+    low: usize,
+    high: usize,
+}
+
+impl Rule {
+    pub fn letter(&self) -> char {
+        return self.letter;
+    }
+
+    pub fn low(&self) -> usize {
+        return self.low;
+    }
+
+    pub fn high(&self) -> usize {
+        return self.high;
+    }
+}
+
+fn parse_low_high(line: &str, range_str: &str) -> Result<(usize, usize), ParseError> {
+    let split_str: Vec<&str> = range_str.split('-').collect();
+
+    if split_str.len() != 2 {
+        return Err(ParseError::new(line, range_str, "expected a range in the form low-high"));
+    }
+
+    let low = parse_number::<usize>(line, split_str[0])?;
+    let high = parse_number::<usize>(line, split_str[1])?;
+
+    return Ok((low, high));
+}
+
+fn parse_rule(line: &str, rule_str: &str) -> Result<Rule, ParseError> {
+    let split_str: Vec<&str> = rule_str.split(' ').collect();
+
+    if split_str.len() != 2 {
+        return Err(ParseError::new(line, rule_str, "expected a rule in the form low-high letter"));
+    }
+
+    let (low, high) = parse_low_high(line, split_str[0])?;
+    let mut letters = split_str[1].chars();
+
+    return match (letters.next(), letters.next()) {
+        (Some(letter), None) => Ok(Rule { letter, low, high }),
+        _ => Err(ParseError::new(line, split_str[1], "expected a single letter")),
+    };
+}
+
+pub fn parse_line(line: &str) -> Result<(Rule, String), ParseError> {
+    let split_line: Vec<&str> = line.split(": ").collect();
+
+    if split_line.len() != 2 {
+        return Err(ParseError::new(line, line, "expected a rule and a password separated by ': '"));
+    }
+
+    let rule = parse_rule(line, split_line[0])?;
+    let word = split_line[1];
+
+    return Ok((rule, word.to_string()));
+}
+
+// Returns the parsed lines together with the number of skipped ones.
+pub fn parse_lines(input: &str, mode: ParseMode) -> Result<(Vec<(Rule, String)>, usize), ParseErrors> {
+    return parse_lines_checked(input, mode, parse_line);
+}
+
+pub fn read_lines(path: &Path, mode: ParseMode) -> Result<(Vec<(Rule, String)>, usize), LoadError> {
+    return load(path, |input| parse_lines(input, mode));
+}
+
+// The same counts as `count_valid_by_policy` over `read_lines`, without ever
+// holding more than one line. Returns them together with the number of
+// skipped lines, the path is only used in messages.
+pub fn count_valid_streaming<'a, R: BufRead>(
+    reader: R, path: &Path, mode: ParseMode, policies: &[&'a dyn PasswordPolicy]
+) -> Result<(PolicyCounts<'a>, usize), LoadError> {
+    let mut counts = vec![0; policies.len()];
+
+    let skipped = stream_lines_checked(reader, path, mode, |_, line| {
+        let (rule, word) = parse_line(line)?;
+
+        for (count, policy) in counts.iter_mut().zip(policies) {
+            if policy.accepts(&rule, &word) {
+                *count += 1;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    return Ok((policies.iter().map(|policy| policy.name()).zip(counts).collect(), skipped));
+}
+
+pub fn count_valid_in_file<'a>(
+    path: &Path, mode: ParseMode, policies: &[&'a dyn PasswordPolicy]
+) -> Result<(PolicyCounts<'a>, usize), LoadError> {
+    let reader = open_input(path).map_err(|error| LoadError::Io { path: path.to_path_buf(), error })?;
+
+    return count_valid_streaming(reader, path, mode, policies);
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Rule, String)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Rule, String)>, ParseErrors> {
+        return parse_lines(input, ParseMode::Strict).map(|(lines, _)| lines);
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Vec<(Rule, String)>, usize), ParseErrors> {
+        return parse_lines(input, ParseMode::Lenient);
+    }
+
+    fn part_one(rules_with_words: &Vec<(Rule, String)>) -> Option<usize> {
+        return Some(count_valid(rules_with_words, &SledPolicy));
+    }
+
+    fn part_two(rules_with_words: &Vec<(Rule, String)>) -> Option<usize> {
+        return Some(count_valid(rules_with_words, &TobogganPolicy));
+    }
+}
@@ -0,0 +1,143 @@
+use super::Rule;
+
+// Positions are counted in chars from 1, so 0 is never in the password.
+fn letter_at(password: &str, position: usize, letter: char) -> bool {
+    return position.checked_sub(1).and_then(|i| password.chars().nth(i)) == Some(letter);
+}
+
+// Whether the letter is at either position, scanning the password only once.
+fn letter_at_both(password: &str, first: usize, second: usize, letter: char) -> (bool, bool) {
+    let (low, high) = (first.min(second), first.max(second));
+    let mut chars = password.chars();
+    let at_low = low.checked_sub(1).and_then(|i| chars.nth(i));
+    let at_high = match (low, high - low) {
+        (_, 0) => at_low,
+        // nothing was taken from the iterator yet
+        (0, _) => chars.nth(high - 1),
+        (_, distance) => chars.nth(distance - 1),
+    };
+    let (at_first, at_second) = if first <= second { (at_low, at_high) } else { (at_high, at_low) };
+
+    return (at_first == Some(letter), at_second == Some(letter));
+}
+
+fn count_letter(password: &str, letter: char) -> usize {
+    // an ASCII byte is never part of a longer UTF-8 sequence
+    if letter.is_ascii() {
+        return password.bytes().filter(|byte| *byte == letter as u8).count();
+    }
+
+    return password.chars().filter(|ch| *ch == letter).count();
+}
+
+// A way of reading the rule in front of a password.
+pub trait PasswordPolicy {
+    fn name(&self) -> &str;
+    fn description(&self) -> &str;
+    fn accepts(&self, rule: &Rule, password: &str) -> bool;
+}
+
+// The policy of the sled rental place down the street.
+pub struct SledPolicy;
+
+impl PasswordPolicy for SledPolicy {
+    fn name(&self) -> &str {
+        return "sled";
+    }
+
+    fn description(&self) -> &str {
+        return "the letter occurs at least low and at most high times";
+    }
+
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        let count = count_letter(password, rule.letter());
+
+        return count >= rule.low() && count <= rule.high();
+    }
+}
+
+// The policy of the Official Toboggan Corporate Authentication System.
+pub struct TobogganPolicy;
+
+impl PasswordPolicy for TobogganPolicy {
+    fn name(&self) -> &str {
+        return "toboggan";
+    }
+
+    fn description(&self) -> &str {
+        return "the letter is at exactly one of the positions low and high, counted from 1";
+    }
+
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        let (at_low, at_high) = letter_at_both(password, rule.low(), rule.high(), rule.letter());
+
+        return at_low != at_high;
+    }
+}
+
+// Half of the toboggan policy each, meant for composing policy expressions.
+pub struct AtLowPolicy;
+
+impl PasswordPolicy for AtLowPolicy {
+    fn name(&self) -> &str {
+        return "at-low";
+    }
+
+    fn description(&self) -> &str {
+        return "the letter is at the position low, counted from 1";
+    }
+
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        return letter_at(password, rule.low(), rule.letter());
+    }
+}
+
+pub struct AtHighPolicy;
+
+impl PasswordPolicy for AtHighPolicy {
+    fn name(&self) -> &str {
+        return "at-high";
+    }
+
+    fn description(&self) -> &str {
+        return "the letter is at the position high, counted from 1";
+    }
+
+    fn accepts(&self, rule: &Rule, password: &str) -> bool {
+        return letter_at(password, rule.high(), rule.letter());
+    }
+}
+
+pub const POLICIES: [&dyn PasswordPolicy; 4] = [&SledPolicy, &TobogganPolicy, &AtLowPolicy, &AtHighPolicy];
+
+pub fn find_policy(name: &str) -> Option<&'static dyn PasswordPolicy> {
+    return POLICIES.iter().copied().find(|policy| policy.name() == name);
+}
+
+pub fn count_valid(rules_with_words: &[(Rule, String)], policy: &dyn PasswordPolicy) -> usize {
+    return rules_with_words
+        .iter()
+        .filter(|(rule, word)| policy.accepts(rule, word))
+        .count();
+}
+
+// The number of accepted passwords next to the name of each policy.
+pub type PolicyCounts<'a> = Vec<(&'a str, usize)>;
+
+// Checks every line against all the policies in a single pass, the counts
+// are in the order of `policies`.
+pub fn count_valid_by_policy<'a>(
+    rules_with_words: &[(Rule, String)], policies: &[&'a dyn PasswordPolicy]
+) -> PolicyCounts<'a> {
+    let mut counts = vec![0; policies.len()];
+
+    for (rule, word) in rules_with_words {
+        for (count, policy) in counts.iter_mut().zip(policies) {
+            if policy.accepts(rule, word) {
+                *count += 1;
+            }
+        }
+    }
+
+    return policies.iter().map(|policy| policy.name()).zip(counts).collect();
+}
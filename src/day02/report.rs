@@ -0,0 +1,110 @@
+// A line by line account of why the passwords pass or fail, for reviewing
+// a password file rather than only counting.
+
+use std::{fmt, path::Path};
+
+use super::{Rule, parse_line, policy::PasswordPolicy};
+use crate::{ParseErrors, ParseMode, LoadError, csv, error::{load, parse_numbered_lines_checked}};
+
+pub struct LineReport<'a> {
+    pub line: usize,
+    pub rule: Rule,
+    pub password: String,
+    pub letter_count: usize,
+    // where the letter occurs, counted in chars from 1
+    pub positions: Vec<usize>,
+    pub verdicts: Vec<(&'a str, bool)>,
+}
+
+impl<'a> LineReport<'a> {
+    pub fn new(line: usize, rule: Rule, password: String, policies: &[&'a dyn PasswordPolicy]) -> LineReport<'a> {
+        let positions: Vec<usize> = password
+            .chars()
+            .enumerate()
+            .filter(|(_, ch)| *ch == rule.letter())
+            .map(|(i, _)| i + 1)
+            .collect();
+
+        let verdicts = policies
+            .iter()
+            .map(|policy| (policy.name(), policy.accepts(&rule, &password)))
+            .collect();
+
+        return LineReport { line, letter_count: positions.len(), positions, verdicts, rule, password };
+    }
+
+    fn positions_text(&self) -> String {
+        let positions: Vec<String> = self.positions.iter().map(|position| position.to_string()).collect();
+
+        return positions.join(" ");
+    }
+}
+
+fn verdict_text(accepted: bool) -> &'static str {
+    return if accepted { "accepted" } else { "rejected" };
+}
+
+impl fmt::Display for LineReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "line {}: {}-{} {}: {}\n    count of '{}': {}",
+            self.line, self.rule.low(), self.rule.high(), self.rule.letter(), self.password,
+            self.rule.letter(), self.letter_count,
+        )?;
+
+        if !self.positions.is_empty() {
+            write!(f, ", at positions {}", self.positions_text())?;
+        }
+
+        let verdicts: Vec<String> = self.verdicts
+            .iter()
+            .map(|(name, accepted)| format!("{}: {}", name, verdict_text(*accepted)))
+            .collect();
+
+        return write!(f, "\n    {}", verdicts.join(", "));
+    }
+}
+
+pub fn csv_header(policies: &[&dyn PasswordPolicy]) -> String {
+    let mut fields: Vec<String> = ["line", "low", "high", "letter", "password", "count", "positions"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    fields.extend(policies.iter().map(|policy| policy.name().to_string()));
+
+    return csv::record(&fields);
+}
+
+pub fn csv_row(report: &LineReport) -> String {
+    let mut fields = vec![
+        report.line.to_string(),
+        report.rule.low().to_string(),
+        report.rule.high().to_string(),
+        report.rule.letter().to_string(),
+        report.password.clone(),
+        report.letter_count.to_string(),
+        report.positions_text(),
+    ];
+
+    fields.extend(report.verdicts.iter().map(|(_, accepted)| verdict_text(*accepted).to_string()));
+
+    return csv::record(&fields);
+}
+
+// Like `parse_lines`, but keeps the line numbers of the input. Returns the
+// reports together with the number of skipped lines.
+pub fn build_report<'a>(
+    input: &str, mode: ParseMode, policies: &[&'a dyn PasswordPolicy]
+) -> Result<(Vec<LineReport<'a>>, usize), ParseErrors> {
+    return parse_numbered_lines_checked(input, mode, |number, line| {
+        let (rule, password) = parse_line(line)?;
+        Ok(LineReport::new(number, rule, password, policies))
+    });
+}
+
+pub fn read_report<'a>(
+    path: &Path, mode: ParseMode, policies: &[&'a dyn PasswordPolicy]
+) -> Result<(Vec<LineReport<'a>>, usize), LoadError> {
+    return load(path, |input| build_report(input, mode, policies));
+}
@@ -1,139 +1,202 @@
-use std::{ops::{Index, Add}, fs::read_to_string, path::Path, env::args};
-
-struct Position {
-    x: usize,
-    y: usize,
+use std::{env::args, ops::RangeInclusive, str::FromStr};
+
+use aoc20::{
+    day03::{
+        read_grid, count_trees_with_edge, interesting_slopes, rank_slopes, Edge, Slope, TreeGrid,
+        render::{render_route, render_svg},
+    },
+    input::{input_path, usage_error},
+};
+
+const USAGE: &str = "\
+Usage: day03 [<input file>] [options]
+
+Options:
+    --slope <right>,<down>
+                       count the trees for this slope instead, can be given
+                       more than once. Negative steps go left, and both can be
+                       fractions like 1/2
+    --edge <edge>      what happens at the left and the right edge: `wrap`
+                       around (the default), `stop` or `reflect`
+    --render <format>  draw the routes instead, `ascii` marks the open squares
+                       passed with O and the trees with X, `svg` draws all the
+                       routes in different colours. The routes are the ones
+                       given with --slope, or else 3,1 for `ascii` and the
+                       five slopes of the puzzle for `svg`
+    --search           count the trees for every slope in the ranges below and
+                       rank them, from the fewest trees to the most
+    --x <min>-<max>    the steps to the right to search, 1-7 by default
+    --y <min>-<max>    the steps down to search, 1-2 by default";
+
+// Either `min-max` or a single number, both of which can be negative.
+fn parse_range<T: FromStr + PartialOrd>(option: &str, value: Option<String>) -> RangeInclusive<T> {
+    let value = value.unwrap_or_else(|| usage_error(USAGE, &format!("Expected a range after {}", option)));
+    let (min, max) = match value.get(1..).and_then(|rest| rest.find('-')) {
+        Some(i) => (&value[..i + 1], &value[i + 2..]),
+        None => (value.as_str(), value.as_str()),
+    };
+
+    return match (min.parse(), max.parse()) {
+        (Ok(min), Ok(max)) if min <= max => min..=max,
+        _ => usage_error(USAGE, &format!("Expected a range like 1-7 after {}, found {}", option, value)),
+    };
 }
 
-struct Slope {
-    x: usize,
-    y: usize,
+// A whole number or a fraction like 1/2.
+fn parse_fraction<T: FromStr>(text: &str) -> Option<(T, usize)> {
+    return match text.split_once('/') {
+        Some((numerator, denominator)) => Some((numerator.parse().ok()?, denominator.parse().ok()?)),
+        None => Some((text.parse().ok()?, 1)),
+    };
 }
 
-impl Add<&Slope> for Position {
-    type Output = Self;
+fn parse_slope(value: Option<String>) -> Slope {
+    let value = value.unwrap_or_else(|| usage_error(USAGE, "Expected a slope after --slope"));
 
-    fn add(self, other: &Slope) -> Position {
-        return Position { x: self.x + other.x, y: self.y + other.y }
-    }
-}
+    let slope = value
+        .split_once(',')
+        .and_then(|(x, y)| Some((parse_fraction(x)?, parse_fraction(y)?)))
+        .and_then(|(x, y)| Slope::from_fractions(x, y));
 
-struct TreeLine {
-    elems: Vec<bool>,
+    return slope.unwrap_or_else(|| usage_error(
+        USAGE, &format!("Expected a slope like 3,1 or -1/2,1, found {}", value)
+    ));
 }
 
-impl TreeLine {
-    fn width(&self) -> usize {
-        return self.elems.len();
-    }
-
-    fn from_line(line: &str) -> TreeLine {
-        return TreeLine {
-            elems: line.chars().map(|ch| ch == '#').collect(),
-        }
-    }
+fn parse_edge(value: Option<String>) -> Edge {
+    return match value.as_deref() {
+        Some("wrap") => Edge::Wrap,
+        Some("stop") => Edge::Stop,
+        Some("reflect") => Edge::Reflect,
+        Some(edge) => usage_error(USAGE, &format!("Unknown edge: {}", edge)),
+        None => usage_error(USAGE, "Expected an edge after --edge"),
+    };
 }
 
-struct TreeGrid {
-    height: usize,
-    base_width: usize,
-    elems: Vec<bool>,
+#[derive(PartialEq)]
+enum RenderFormat {
+    Ascii,
+    Svg,
 }
 
-impl TreeGrid {
-    fn append(&mut self, next: &TreeLine) {
-        if self.height == 0 {
-            self.base_width = next.width();
-        }
-
-        if next.width() != self.base_width {
-            panic!("Tree line width does not match tree grid width");
-        }
-
-        self.elems.extend(&next.elems);
-        self.height += 1;
-    }
+fn parse_render_format(value: Option<String>) -> RenderFormat {
+    return match value.as_deref() {
+        Some("ascii") => RenderFormat::Ascii,
+        Some("svg") => RenderFormat::Svg,
+        Some(format) => usage_error(USAGE, &format!("Unknown format: {}", format)),
+        None => usage_error(USAGE, "Expected a format after --render"),
+    };
+}
 
-    fn new() -> TreeGrid {
-        return TreeGrid {
-            height: 0,
-            base_width: 0,
-            elems: Vec::new(),
-        }
+fn render(grid: &TreeGrid, slopes: &[Slope], edge: Edge, format: RenderFormat) {
+    if format == RenderFormat::Svg {
+        let slopes = if slopes.is_empty() { interesting_slopes().to_vec() } else { slopes.to_vec() };
+        return print!("{}", render_svg(grid, &slopes, edge));
     }
 
-    fn from_lines<I>(lines: I) -> TreeGrid
-    where I: Iterator<Item = TreeLine> {
-        let mut grid = TreeGrid::new();
+    let slopes = if slopes.is_empty() { vec![Slope { x: 3, y: 1 }] } else { slopes.to_vec() };
 
-        for line in lines {
-            grid.append(&line);
+    for (i, slope) in slopes.iter().enumerate() {
+        if i > 0 {
+            println!();
         }
 
-        return grid;
+        println!("{}", describe(slope, count_trees_with_edge(grid, slope, edge)));
+        println!("{}", render_route(grid, slope, edge));
     }
 }
 
-impl Index<&Position> for TreeGrid {
-    type Output = bool;
+fn describe(slope: &Slope, trees: u32) -> String {
+    return format!("right {}, down {} ({} trees)", slope.x, slope.y, trees);
+}
+
+fn search(grid: &TreeGrid, xs: RangeInclusive<isize>, ys: RangeInclusive<usize>, edge: Edge) {
+    let ranked = rank_slopes(grid, xs, ys, edge);
 
-    fn index(&self, p: &Position) -> &bool {
-        let x_reduced = p.x % self.base_width;
+    let (fewest, most) = match (ranked.first(), ranked.last()) {
+        (Some(fewest), Some(most)) => (fewest, most),
+        _ => usage_error(USAGE, "No slope goes down, --y has to include more than 0"),
+    };
 
-        if p.y > self.height {
-            panic!("Index out of bounds");
-        }
+    println!("Fewest trees: {}", describe(&fewest.0, fewest.1));
+    println!("Most trees: {}", describe(&most.0, most.1));
+    println!();
+    println!("rank  right  down  trees");
 
-        return &self.elems[p.y*self.base_width + x_reduced];
+    for (rank, (slope, trees)) in ranked.iter().enumerate() {
+        println!("{:>4}  {:>5}  {:>4}  {:>5}", rank + 1, slope.x, slope.y, trees);
     }
 }
 
-fn read_grid(path: &Path) -> TreeGrid {
-    return TreeGrid::from_lines(read_to_string(path)
-        .expect("Input file not found")
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(TreeLine::from_line));
-}
+fn main() {
+    let mut search_mode = false;
+    let mut ranges_given = false;
+    let mut xs = 1..=7;
+    let mut ys = 1..=2;
+    let mut slopes: Vec<Slope> = Vec::new();
+    let mut edge = Edge::Wrap;
+    let mut render_format: Option<RenderFormat> = None;
+    let mut path: Option<String> = None;
+    let mut args = args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--search" => search_mode = true,
+            "--x" => {
+                xs = parse_range("--x", args.next());
+                ranges_given = true;
+            }
+            "--y" => {
+                ys = parse_range("--y", args.next());
+                ranges_given = true;
+            }
+            "--slope" => slopes.push(parse_slope(args.next())),
+            "--edge" => edge = parse_edge(args.next()),
+            "--render" => render_format = Some(parse_render_format(args.next())),
+            flag if flag.starts_with("--") => usage_error(USAGE, &format!("Unknown option: {}", flag)),
+            _ if path.is_none() => path = Some(arg),
+            _ => usage_error(USAGE, "Unexpected arguments!"),
+        }
+    }
 
-fn count_trees(grid: &TreeGrid, slope: &Slope) -> u32 {
-    let mut current_position: Position = Position { x: 0, y: 0 };
-    let mut seen_trees: u32 = 0;
+    if ranges_given && !search_mode {
+        usage_error(USAGE, "--x and --y only apply to --search");
+    }
 
-    while current_position.y < grid.height {
-        seen_trees += if grid[&current_position] { 1 } else { 0 };
-        current_position = current_position + &slope;
+    if search_mode && !slopes.is_empty() {
+        usage_error(USAGE, "--slope can not be combined with --search");
     }
 
-    return seen_trees;
-}
+    if search_mode && render_format.is_some() {
+        usage_error(USAGE, "--render can not be combined with --search");
+    }
 
-fn main() {
-    let args: Vec<String> = args().collect();
+    let path_to_input = input_path(path.as_deref());
+    let grid = read_grid(path_to_input).unwrap_or_else(|err| err.exit());
 
-    if args.len() < 2 {
-        panic!("Expected an argument");
+    if search_mode {
+        return search(&grid, xs, ys, edge);
     }
 
-    let path_to_input = Path::new(&args[1]);
-    let grid = read_grid(path_to_input);
+    if let Some(format) = render_format {
+        return render(&grid, &slopes, edge, format);
+    }
 
-    let tree_count = count_trees(&grid, &Slope { x: 3, y: 1 });
+    if !slopes.is_empty() {
+        for slope in slopes.iter() {
+            println!("{}", describe(slope, count_trees_with_edge(&grid, slope, edge)));
+        }
 
-    let interesting_slopes = vec![
-        Slope { x: 1, y: 1 },
-        Slope { x: 3, y: 1 },
-        Slope { x: 5, y: 1 },
-        Slope { x: 7, y: 1 },
-        Slope { x: 1, y: 2 }
-    ];
+        return;
+    }
+
+    let tree_count = count_trees_with_edge(&grid, &Slope { x: 3, y: 1 }, edge);
 
-    let interesting_slopes_product: u32 = interesting_slopes
+    let interesting_slopes_product: u32 = interesting_slopes()
         .iter()
-        .map(|is| count_trees(&grid, is))
+        .map(|is| count_trees_with_edge(&grid, is, edge))
         .product();
 
     println!("Counted {} trees!", tree_count);
     println!("Product of tree counts for interesting slopes: {}!", interesting_slopes_product);
 }
-
@@ -0,0 +1,336 @@
+pub mod render;
+
+use std::{fmt, iter::successors, ops::{Index, RangeInclusive}, path::Path};
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::load, grid::Grid};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub x: isize,
+    pub y: usize,
+}
+
+// Steps to the right (negative ones go left) and down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Slope {
+    pub x: isize,
+    pub y: usize,
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    return if b == 0 { a } else { gcd(b, a % b) };
+}
+
+impl Slope {
+    // A slope of `x_numerator / x_denominator` to the right for every
+    // `y_numerator / y_denominator` down. The toboggan only touches the grid
+    // where it crosses a whole position, so this is the shortest whole step
+    // along it - one right and two down for 1/2 and 1. None when a
+    // denominator is 0 or the step does not fit.
+    pub fn from_fractions(x: (isize, usize), y: (usize, usize)) -> Option<Slope> {
+        let ((x_numerator, x_denominator), (y_numerator, y_denominator)) = (x, y);
+
+        if x_denominator == 0 || y_denominator == 0 {
+            return None;
+        }
+
+        // in lowest terms, the step has to be a multiple of both denominators
+        let x_divisor = gcd(x_numerator.unsigned_abs() as u128, x_denominator as u128);
+        let y_divisor = gcd(y_numerator as u128, y_denominator as u128);
+        let (x_numerator, x_denominator) = (x_numerator as i128 / x_divisor as i128, x_denominator as u128 / x_divisor);
+        let (y_numerator, y_denominator) = (y_numerator as u128 / y_divisor, y_denominator as u128 / y_divisor);
+        let steps = x_denominator / gcd(x_denominator, y_denominator) * y_denominator;
+
+        let x = x_numerator * (steps / x_denominator) as i128;
+        let y = y_numerator * (steps / y_denominator);
+
+        return Some(Slope { x: isize::try_from(x).ok()?, y: usize::try_from(y).ok()? });
+    }
+}
+
+// What happens when the toboggan reaches the left or the right edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    // the grid repeats to both sides
+    Wrap,
+    // the route ends
+    Stop,
+    // the grid is mirrored at the edges, so the toboggan bounces back
+    Reflect,
+}
+
+// Every row is packed into bits, a set bit is a tree.
+pub struct TreeGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl TreeGrid {
+    pub fn from_grid(trees: &Grid<bool>) -> TreeGrid {
+        let words_per_row = trees.width().div_ceil(64);
+        let mut bits = vec![0; words_per_row * trees.height()];
+
+        for (x, y) in trees.positions() {
+            if trees[(x, y)] {
+                bits[y * words_per_row + x / 64] |= 1 << (x % 64);
+            }
+        }
+
+        return TreeGrid { width: trees.width(), height: trees.height(), words_per_row, bits };
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        return &self.bits[y * self.words_per_row..(y + 1) * self.words_per_row];
+    }
+
+    fn has_tree(&self, x: usize, y: usize) -> bool {
+        return has_tree(self.row(y), x);
+    }
+
+    // None once the position is below the grid, or beside it with `Edge::Stop`.
+    pub fn tree_at(&self, p: &Position, edge: Edge) -> Option<bool> {
+        let width = self.width as isize;
+
+        if p.y >= self.height || width == 0 {
+            return None;
+        }
+
+        return column(p.x, width, edge).map(|x| self.has_tree(x, p.y));
+    }
+}
+
+fn has_tree(row: &[u64], x: usize) -> bool {
+    return row[x / 64] & 1 << (x % 64) != 0;
+}
+
+// The column of the grid at `x`, None when it is beside the grid with `Edge::Stop`.
+fn column(x: isize, width: isize, edge: Edge) -> Option<usize> {
+    let x = match edge {
+        Edge::Wrap => x.rem_euclid(width),
+        Edge::Stop if x < 0 || x >= width => return None,
+        Edge::Stop => x,
+        Edge::Reflect => {
+            let x = x.rem_euclid(2 * width);
+            if x < width { x } else { 2 * width - 1 - x }
+        }
+    };
+
+    return Some(x as usize);
+}
+
+impl Index<&Position> for TreeGrid {
+    type Output = bool;
+
+    // The grid repeats to both sides, so only `y` can be out of bounds.
+    fn index(&self, p: &Position) -> &bool {
+        if p.y >= self.height {
+            panic!("Index out of bounds");
+        }
+
+        let tree = self.has_tree(p.x.rem_euclid(self.width as isize) as usize, p.y);
+
+        return if tree { &true } else { &false };
+    }
+}
+
+impl fmt::Display for TreeGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..self.width {
+                write!(f, "{}", if self.has_tree(x, y) { '#' } else { '.' })?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+pub fn parse_grid(input: &str) -> Result<TreeGrid, ParseError> {
+    let trees = Grid::parse(input, "expected '#' or '.'", |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    return Ok(TreeGrid::from_grid(&trees));
+}
+
+pub fn read_grid(path: &Path) -> Result<TreeGrid, LoadError> {
+    return load(path, parse_grid);
+}
+
+pub fn count_trees(grid: &TreeGrid, slope: &Slope) -> u32 {
+    return count_trees_with_edge(grid, slope, Edge::Wrap);
+}
+
+// How far right the toboggan is. With `Edge::Wrap` and `Edge::Reflect` the
+// grid looks the same again after a period, so both the position and the step
+// are kept within one and the toboggan can go on for as long as it likes.
+#[derive(Clone, Copy)]
+struct Walk {
+    x: isize,
+    step: isize,
+    // 0 with `Edge::Stop`, which has none
+    period: isize,
+}
+
+impl Walk {
+    fn new(width: usize, step: isize, edge: Edge) -> Walk {
+        let period = match edge {
+            Edge::Wrap => width as isize,
+            Edge::Stop => 0,
+            Edge::Reflect => 2 * width as isize,
+        };
+
+        if period == 0 {
+            return Walk { x: 0, step, period };
+        }
+
+        return Walk { x: 0, step: step.rem_euclid(period), period };
+    }
+
+    // None once the toboggan is too far beside the grid to ever come back.
+    fn advance(self) -> Option<Walk> {
+        let x = if self.period == 0 { self.x.checked_add(self.step)? } else { (self.x + self.step) % self.period };
+
+        return Some(Walk { x, ..self });
+    }
+}
+
+// Every position the toboggan passes, and whether there is a tree. The
+// positions are the ones in the grid, where the route is folded back into it.
+pub fn route(grid: &TreeGrid, slope: Slope, edge: Edge) -> impl Iterator<Item = (Position, bool)> + '_ {
+    let width = grid.width() as isize;
+    let start = (Walk::new(grid.width(), slope.x, edge), 0);
+
+    // a slope which does not go down never leaves the row, and a row which
+    // does not fit is far below the grid anyway
+    let next = move |(walk, y): &(Walk, usize)| {
+        if slope.y == 0 {
+            return None;
+        }
+
+        return Some((walk.advance()?, y.checked_add(slope.y)?));
+    };
+
+    return successors(Some(start), next).map_while(move |(walk, y)| {
+        let tree = grid.tree_at(&Position { x: walk.x, y }, edge)?;
+
+        return Some((Position { x: column(walk.x, width, edge)? as isize, y }, tree));
+    });
+}
+
+pub fn count_trees_with_edge(grid: &TreeGrid, slope: &Slope, edge: Edge) -> u32 {
+    return route(grid, *slope, edge).filter(|(_, tree)| *tree).count() as u32;
+}
+
+pub fn interesting_slopes() -> [Slope; 5] {
+    return [
+        Slope { x: 1, y: 1 },
+        Slope { x: 3, y: 1 },
+        Slope { x: 5, y: 1 },
+        Slope { x: 7, y: 1 },
+        Slope { x: 1, y: 2 }
+    ];
+}
+
+// The same as `count_trees_with_edge` for each of the slopes, but going over
+// the rows only once. The slopes going down by the same number of rows meet
+// the same rows, and every slope keeps where it is along the row.
+pub fn count_trees_for_slopes(grid: &TreeGrid, slopes: &[Slope], edge: Edge) -> Vec<u32> {
+    let width = grid.width() as isize;
+    let mut counts = vec![0; slopes.len()];
+    let mut walks: Vec<Option<Walk>> = slopes.iter().map(|slope| Some(Walk::new(grid.width(), slope.x, edge))).collect();
+    let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+
+    if width == 0 {
+        return counts;
+    }
+
+    for (i, slope) in slopes.iter().enumerate() {
+        match groups.iter_mut().find(|(down, _)| *down == slope.y) {
+            Some((_, group)) => group.push(i),
+            None => groups.push((slope.y, vec![i])),
+        }
+    }
+
+    for y in 0..grid.height() {
+        let row = grid.row(y);
+
+        // a slope which does not go down never leaves the first row
+        for (_, group) in groups.iter().filter(|(down, _)| if *down == 0 { y == 0 } else { y % down == 0 }) {
+            for &i in group {
+                let walk = match walks[i] {
+                    Some(walk) => walk,
+                    None => continue,
+                };
+
+                match column(walk.x, width, edge) {
+                    Some(x) => counts[i] += has_tree(row, x) as u32,
+                    None => {
+                        walks[i] = None;
+                        continue;
+                    }
+                }
+
+                walks[i] = walk.advance();
+            }
+        }
+    }
+
+    return counts;
+}
+
+// Counts the trees for every slope with `x` and `y` in the ranges, ranked from
+// the fewest trees to the most, and by `x` and `y` on a tie. Slopes which do
+// not go down are left out.
+pub fn rank_slopes(
+    grid: &TreeGrid, xs: RangeInclusive<isize>, ys: RangeInclusive<usize>, edge: Edge
+) -> Vec<(Slope, u32)> {
+    let slopes: Vec<Slope> = xs
+        .flat_map(|x| ys.clone().filter(|y| *y > 0).map(move |y| Slope { x, y }))
+        .collect();
+    let counts = count_trees_for_slopes(grid, &slopes, edge);
+    let mut ranked: Vec<(Slope, u32)> = slopes.into_iter().zip(counts).collect();
+
+    ranked.sort_by_key(|(slope, trees)| (*trees, slope.x, slope.y));
+
+    return ranked;
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = TreeGrid;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<TreeGrid, ParseErrors> {
+        return Ok(parse_grid(input)?);
+    }
+
+    fn part_one(grid: &TreeGrid) -> Option<u32> {
+        return Some(count_trees(grid, &Slope { x: 3, y: 1 }));
+    }
+
+    fn part_two(grid: &TreeGrid) -> Option<u32> {
+        return Some(interesting_slopes()
+            .iter()
+            .map(|is| count_trees(grid, is))
+            .product());
+    }
+}
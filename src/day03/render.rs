@@ -0,0 +1,147 @@
+// Pictures of the routes the toboggan takes, as text and as SVG.
+
+use std::ops::Range;
+
+use super::{TreeGrid, Slope, Edge, Position, route, column};
+use crate::grid::Grid;
+
+const CELL_SIZE: isize = 10;
+
+const TREE_COLOUR: &str = "#a5d6a7";
+
+// One for every slope, starting over when there are more slopes.
+const ROUTE_COLOURS: [&str; 6] = ["#d62728", "#1f77b4", "#ff7f0e", "#9467bd", "#8c564b", "#e377c2"];
+
+// The widest picture drawn with the copies of the grid side by side, wider
+// ones show the routes folded onto a single copy instead.
+const MAX_COLUMNS: usize = 1000;
+
+struct Columns {
+    range: Range<isize>,
+    folded: bool,
+}
+
+impl Columns {
+    // Whole copies of the grid side by side, enough for every position of the
+    // routes, given by their slopes and number of steps.
+    fn new(grid: &TreeGrid, routes: &[(Slope, usize)]) -> Columns {
+        let width = grid.width() as i128;
+
+        if width == 0 {
+            return Columns { range: 0..0, folded: false };
+        }
+
+        let lasts = routes.iter().map(|(slope, steps)| unfolded_x(slope, steps.saturating_sub(1)));
+        let min = lasts.clone().min().unwrap_or(0).min(0);
+        let max = lasts.max().unwrap_or(0).max(0);
+        let (start, end) = (min.div_euclid(width) * width, (max.div_euclid(width) + 1) * width);
+
+        if end - start > MAX_COLUMNS.max(grid.width()) as i128 {
+            return Columns { range: 0..width as isize, folded: true };
+        }
+
+        return Columns { range: start as isize..end as isize, folded: false };
+    }
+
+    // Where a position is drawn, counted from the left of the picture.
+    fn of(&self, x: i128, width: usize, edge: Edge) -> usize {
+        if self.folded {
+            let x = x.rem_euclid(2 * width as i128) as isize;
+            return column(x, width as isize, edge).unwrap_or(0);
+        }
+
+        return (x - self.range.start as i128) as usize;
+    }
+}
+
+// How far right the route is after the given number of steps, before it is
+// folded into the grid. This cannot overflow, unlike the position itself.
+fn unfolded_x(slope: &Slope, step: usize) -> i128 {
+    return step as i128 * slope.x as i128;
+}
+
+// The grid as far as the route goes, with `O` for the open squares and `X`
+// for the trees the toboggan passes.
+pub fn render_route(grid: &TreeGrid, slope: &Slope, edge: Edge) -> Grid<char> {
+    let visited: Vec<(Position, bool)> = route(grid, *slope, edge).collect();
+    let columns = Columns::new(grid, &[(*slope, visited.len())]);
+    let width = columns.range.len();
+
+    let mut cells: Vec<char> = (0..grid.height())
+        .flat_map(|y| columns.range.clone().map(move |x| Position { x, y }))
+        .map(|p| match grid.tree_at(&p, edge) {
+            Some(true) => '#',
+            Some(false) => '.',
+            None => ' ',
+        })
+        .collect();
+
+    for (step, (p, tree)) in visited.into_iter().enumerate() {
+        let x = columns.of(unfolded_x(slope, step), grid.width(), edge);
+        cells[p.y * width + x] = if tree { 'X' } else { 'O' };
+    }
+
+    return Grid::new(width, grid.height(), cells);
+}
+
+// The same picture for any number of slopes, every route in its own colour.
+// Each position passed is a dot, filled where there is a tree.
+pub fn render_svg(grid: &TreeGrid, slopes: &[Slope], edge: Edge) -> String {
+    let routes: Vec<Vec<(Position, bool)>> = slopes.iter().map(|slope| route(grid, *slope, edge).collect()).collect();
+    let lengths: Vec<(Slope, usize)> = slopes.iter().copied().zip(routes.iter().map(Vec::len)).collect();
+    let columns = Columns::new(grid, &lengths);
+    let width = columns.range.len() as isize * CELL_SIZE;
+    let height = grid.height() as isize * CELL_SIZE;
+    let centre = |slope: &Slope, step: usize, p: &Position| (
+        columns.of(unfolded_x(slope, step), grid.width(), edge) as isize * CELL_SIZE + CELL_SIZE / 2,
+        p.y as isize * CELL_SIZE + CELL_SIZE / 2,
+    );
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+        width, height,
+    );
+    svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" fill=\"#ffffff\"/>\n", width, height));
+
+    for y in 0..grid.height() {
+        for x in columns.range.clone() {
+            if grid.tree_at(&Position { x, y }, edge) == Some(true) {
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{2}\" height=\"{2}\" fill=\"{3}\"/>\n",
+                    (x - columns.range.start) * CELL_SIZE, y as isize * CELL_SIZE, CELL_SIZE, TREE_COLOUR,
+                ));
+            }
+        }
+    }
+
+    for (i, (slope, visited)) in slopes.iter().zip(routes.iter()).enumerate() {
+        let colour = ROUTE_COLOURS[i % ROUTE_COLOURS.len()];
+        let trees = visited.iter().filter(|(_, tree)| *tree).count();
+        let centres: Vec<(isize, isize)> = visited
+            .iter()
+            .enumerate()
+            .map(|(step, (p, _))| centre(slope, step, p))
+            .collect();
+
+        svg.push_str(&format!("<g stroke=\"{0}\" fill=\"{0}\">\n", colour));
+        svg.push_str(&format!("<title>right {}, down {}: {} trees</title>\n", slope.x, slope.y, trees));
+
+        // the lines of a folded route would jump across the grid, so only
+        // its dots are drawn
+        if !columns.folded {
+            let points: Vec<String> = centres.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+            svg.push_str(&format!("<polyline points=\"{}\" fill=\"none\" stroke-width=\"2\"/>\n", points.join(" ")));
+        }
+
+        for ((x, y), (_, tree)) in centres.iter().zip(visited) {
+            let fill = if *tree { colour } else { "#ffffff" };
+            svg.push_str(&format!("<circle cx=\"{}\" cy=\"{}\" r=\"3\" fill=\"{}\"/>\n", x, y, fill));
+        }
+
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+
+    return svg;
+}
@@ -0,0 +1,92 @@
+pub mod schema;
+pub mod report;
+
+use std::path::Path;
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::load};
+use schema::Schema;
+
+pub struct Record {
+    // where the record starts in the input
+    line: usize,
+    fields: Vec<(String, String)>,
+}
+
+impl Record {
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+}
+
+fn parse_field(line: &str, field: &str) -> Result<(String, String), ParseError> {
+    let split_field: Vec<&str> = field.split(':').collect();
+
+    if split_field.len() != 2 {
+        return Err(ParseError::new(line, field, "expected a field in the form key:value"));
+    }
+
+    return Ok((split_field[0].to_string(), split_field[1].to_string()));
+}
+
+// Records are separated by blank lines and their fields may span several lines.
+pub fn parse_records(input: &str) -> Result<Vec<Record>, ParseError> {
+    let mut records = Vec::new();
+    let mut current = Record { line: 1, fields: Vec::new() };
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            if !current.fields.is_empty() {
+                records.push(current);
+            }
+
+            current = Record { line: i + 2, fields: Vec::new() };
+            continue;
+        }
+
+        for field in line.split_whitespace() {
+            current.fields.push(parse_field(line, field).map_err(|err| err.with_line(i + 1))?);
+        }
+    }
+
+    if !current.fields.is_empty() {
+        records.push(current);
+    }
+
+    return Ok(records);
+}
+
+pub fn load_records(path: &Path) -> Result<Vec<Record>, LoadError> {
+    return load(path, parse_records);
+}
+
+// Checks against the built-in schema, the one of the puzzle.
+pub fn count_valid_records(records: &[Record], validate: bool) -> usize {
+    return count_accepted(records, &Schema::default(), validate);
+}
+
+pub fn count_accepted(records: &[Record], schema: &Schema, validate: bool) -> usize {
+    return records
+        .iter()
+        .filter(|record| schema.accepts(record, validate))
+        .count();
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Record>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Record>, ParseErrors> {
+        return Ok(parse_records(input)?);
+    }
+
+    fn part_one(records: &Vec<Record>) -> Option<usize> {
+        return Some(count_valid_records(records, false));
+    }
+
+    fn part_two(records: &Vec<Record>) -> Option<usize> {
+        return Some(count_valid_records(records, true));
+    }
+}
@@ -0,0 +1,101 @@
+// Why each passport passes or fails the schema, for finding out what is
+// wrong with a batch rather than only counting.
+
+use std::fmt;
+
+use super::{Record, schema::Schema};
+use crate::json;
+
+pub struct InvalidField {
+    pub key: String,
+    pub value: String,
+    // the validator from the schema and what is wrong with the value
+    pub rule: String,
+    pub reason: String,
+}
+
+pub struct RecordReport {
+    // counted from 1, in the order of the input
+    pub index: usize,
+    pub line: usize,
+    pub missing: Vec<String>,
+    pub invalid: Vec<InvalidField>,
+}
+
+impl RecordReport {
+    pub fn is_valid(&self) -> bool {
+        return self.missing.is_empty() && self.invalid.is_empty();
+    }
+
+    pub fn to_json(&self) -> String {
+        let missing: Vec<String> = self.missing.iter().map(|key| json::string(key)).collect();
+        let invalid: Vec<String> = self.invalid
+            .iter()
+            .map(|field| json::object(&[
+                ("key", json::string(&field.key)),
+                ("value", json::string(&field.value)),
+                ("rule", json::string(&field.rule)),
+                ("reason", json::string(&field.reason)),
+            ]))
+            .collect();
+
+        return json::object(&[
+            ("index", self.index.to_string()),
+            ("line", self.line.to_string()),
+            ("valid", self.is_valid().to_string()),
+            ("missing", json::array(&missing)),
+            ("invalid", json::array(&invalid)),
+        ]);
+    }
+}
+
+impl fmt::Display for RecordReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let verdict = if self.is_valid() { "valid" } else { "invalid" };
+        write!(f, "passport {} (line {}): {}", self.index, self.line, verdict)?;
+
+        if !self.missing.is_empty() {
+            write!(f, "\n    missing {}", self.missing.join(", "))?;
+        }
+
+        for field in self.invalid.iter() {
+            write!(f, "\n    {}:{} {}", field.key, field.value, field.reason)?;
+        }
+
+        return Ok(());
+    }
+}
+
+// Every field the schema knows is checked, the optional ones too when they
+// are there. With a field given more than once, the last one counts.
+pub fn check_record(schema: &Schema, index: usize, record: &Record) -> RecordReport {
+    let mut missing = Vec::new();
+    let mut invalid = Vec::new();
+
+    for rule in schema.fields.iter() {
+        match record.fields.iter().rev().find(|(key, _)| *key == rule.key) {
+            Some((key, value)) => {
+                if let Err(reason) = rule.validator.check(value) {
+                    invalid.push(InvalidField {
+                        key: key.clone(),
+                        value: value.clone(),
+                        rule: rule.validator.to_string(),
+                        reason,
+                    });
+                }
+            }
+            None if rule.required => missing.push(rule.key.clone()),
+            None => {}
+        }
+    }
+
+    return RecordReport { index, line: record.line, missing, invalid };
+}
+
+pub fn check_records(schema: &Schema, records: &[Record]) -> Vec<RecordReport> {
+    return records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| check_record(schema, i + 1, record))
+        .collect();
+}
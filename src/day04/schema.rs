@@ -0,0 +1,241 @@
+// What a passport has to contain, read from a text file like the built-in
+// `passport-schema.txt`, so that the rules can change without a rebuild.
+
+use std::{fmt, path::Path};
+
+use super::Record;
+use crate::{ParseError, LoadError, error::{load, parse_number}};
+
+pub const DEFAULT_SCHEMA: &str = include_str!("passport-schema.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitRange {
+    pub unit: String,
+    pub min: u32,
+    pub max: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validator {
+    Any,
+    Year { min: u32, max: u32 },
+    Height(Vec<UnitRange>),
+    HexColour,
+    OneOf(Vec<String>),
+    Digits(usize),
+}
+
+fn is_digits(text: &str) -> bool {
+    return !text.is_empty() && text.chars().all(|ch| ch.is_ascii_digit());
+}
+
+impl Validator {
+    pub fn accepts(&self, value: &str) -> bool {
+        return self.check(value).is_ok();
+    }
+
+    // Says what is wrong with the value when it is not accepted.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        return match self {
+            Validator::Any => Ok(()),
+            Validator::Year { min, max } => match value.parse::<u32>() {
+                Ok(year) if value.len() == 4 && is_digits(value) => {
+                    if (*min..=*max).contains(&year) { Ok(()) } else { Err(format!("out of {}-{}", min, max)) }
+                }
+                _ => Err(String::from("expected a year with four digits")),
+            },
+            Validator::Height(ranges) => {
+                let digits = value.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(value.len());
+                let (number, unit) = value.split_at(digits);
+
+                match (number.parse::<u32>(), ranges.iter().find(|range| range.unit == unit)) {
+                    (Ok(number), Some(range)) if (range.min..=range.max).contains(&number) => Ok(()),
+                    (Ok(_), Some(range)) => Err(format!("out of {}-{}{}", range.min, range.max, range.unit)),
+                    _ => {
+                        let units: Vec<&str> = ranges.iter().map(|range| range.unit.as_str()).collect();
+                        Err(format!("expected a number followed by {}", units.join(" or ")))
+                    }
+                }
+            }
+            Validator::HexColour => {
+                let hex = value.len() == 7 && value.starts_with('#')
+                    && value[1..].chars().all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch));
+
+                if hex { Ok(()) } else { Err(String::from("expected a # followed by six hex digits")) }
+            }
+            Validator::OneOf(values) if values.iter().any(|allowed| allowed == value) => Ok(()),
+            Validator::OneOf(values) => Err(format!("expected one of {}", values.join(", "))),
+            Validator::Digits(count) if value.len() == *count && is_digits(value) => Ok(()),
+            Validator::Digits(count) => Err(format!("expected {} digits", count)),
+        };
+    }
+}
+
+// Written the way it is in the schema file.
+impl fmt::Display for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Validator::Any => write!(f, "any"),
+            Validator::Year { min, max } => write!(f, "year {}-{}", min, max),
+            Validator::Height(ranges) => {
+                let ranges: Vec<String> = ranges
+                    .iter()
+                    .map(|range| format!("{}-{}{}", range.min, range.max, range.unit))
+                    .collect();
+
+                write!(f, "height {}", ranges.join(" "))
+            }
+            Validator::HexColour => write!(f, "colour"),
+            Validator::OneOf(values) => write!(f, "one-of {}", values.join(" ")),
+            Validator::Digits(count) => write!(f, "digits {}", count),
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldRule {
+    pub key: String,
+    pub required: bool,
+    pub validator: Validator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+impl Schema {
+    // Without validation the required fields only have to be there, with it
+    // the optional ones have to be valid as well when they are. With a field
+    // given more than once, the last one counts.
+    pub fn accepts(&self, record: &Record, validate: bool) -> bool {
+        return self.fields.iter().all(|rule| {
+            match record.fields.iter().rev().find(|(key, _)| *key == rule.key) {
+                Some((_, value)) => !validate || rule.validator.accepts(value),
+                None => !rule.required,
+            }
+        });
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Schema {
+        return parse_schema(DEFAULT_SCHEMA).expect("the built-in schema is valid");
+    }
+}
+
+fn parse_range(line: &str, text: &str) -> Result<(u32, u32), ParseError> {
+    let (min, max) = match text.split_once('-') {
+        Some(range) => range,
+        None => return Err(ParseError::new(line, text, "expected a range in the form min-max")),
+    };
+
+    let (min, max) = (parse_number::<u32>(line, min)?, parse_number::<u32>(line, max)?);
+
+    if min > max {
+        return Err(ParseError::new(line, text, "the range ends before it starts"));
+    }
+
+    return Ok((min, max));
+}
+
+fn parse_unit_range(line: &str, text: &str) -> Result<UnitRange, ParseError> {
+    let digits = text.rfind(|ch: char| ch.is_ascii_digit()).map_or(0, |i| i + 1);
+    let (range, unit) = text.split_at(digits);
+
+    if unit.is_empty() {
+        return Err(ParseError::new(line, text, "expected a range with a unit, like 150-193cm"));
+    }
+
+    let (min, max) = parse_range(line, range)?;
+
+    return Ok(UnitRange { unit: unit.to_string(), min, max });
+}
+
+fn expect_count(line: &str, name: &str, arguments: &[&str], count: usize) -> Result<(), ParseError> {
+    if arguments.len() == count {
+        return Ok(());
+    }
+
+    let text = arguments.get(count).copied().unwrap_or(name);
+    let message = match count {
+        0 => format!("{} takes no arguments", name),
+        1 => format!("{} takes a single argument", name),
+        _ => format!("{} takes {} arguments", name, count),
+    };
+
+    return Err(ParseError::new(line, text, &message));
+}
+
+fn parse_validator(line: &str, name: &str, arguments: &[&str]) -> Result<Validator, ParseError> {
+    return match name {
+        "any" => expect_count(line, name, arguments, 0).map(|_| Validator::Any),
+        "year" => {
+            expect_count(line, name, arguments, 1)?;
+            let (min, max) = parse_range(line, arguments[0])?;
+            Ok(Validator::Year { min, max })
+        }
+        "height" if arguments.is_empty() => Err(ParseError::new(line, name, "expected at least one range with a unit")),
+        "height" => {
+            let ranges: Result<Vec<UnitRange>, ParseError> = arguments
+                .iter()
+                .map(|argument| parse_unit_range(line, argument))
+                .collect();
+            Ok(Validator::Height(ranges?))
+        }
+        "colour" => expect_count(line, name, arguments, 0).map(|_| Validator::HexColour),
+        "one-of" if arguments.is_empty() => Err(ParseError::new(line, name, "expected at least one value")),
+        "one-of" => Ok(Validator::OneOf(arguments.iter().map(|value| value.to_string()).collect())),
+        "digits" => {
+            expect_count(line, name, arguments, 1)?;
+            Ok(Validator::Digits(parse_number(line, arguments[0])?))
+        }
+        _ => Err(ParseError::new(line, name, "unknown validator, expected one of year, height, colour, one-of, digits or any")),
+    };
+}
+
+fn parse_rule(line: &str) -> Result<FieldRule, ParseError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+
+    let required = match words.get(1) {
+        Some(&"required") => true,
+        Some(&"optional") => false,
+        Some(word) => return Err(ParseError::new(line, word, "expected required or optional")),
+        None => return Err(ParseError::new(line, line.trim_end(), "expected a key followed by required or optional")),
+    };
+
+    let validator = match words.get(2) {
+        Some(name) => parse_validator(line, name, &words[3..])?,
+        None => Validator::Any,
+    };
+
+    return Ok(FieldRule { key: words[0].to_string(), required, validator });
+}
+
+// One field per line, empty lines and the ones starting with `#` are skipped.
+pub fn parse_schema(input: &str) -> Result<Schema, ParseError> {
+    let mut fields: Vec<FieldRule> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let rule = parse_rule(line).map_err(|err| err.with_line(i + 1))?;
+
+        if fields.iter().any(|field| field.key == rule.key) {
+            let key = line.split_whitespace().next().unwrap_or(line);
+            return Err(ParseError::new(line, key, "the field is already described above").with_line(i + 1));
+        }
+
+        fields.push(rule);
+    }
+
+    return Ok(Schema { fields });
+}
+
+pub fn load_schema(path: &Path) -> Result<Schema, LoadError> {
+    return load(path, parse_schema);
+}
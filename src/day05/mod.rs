@@ -0,0 +1,103 @@
+use std::{path::Path, collections::HashSet};
+
+use crate::{Solution, ParseError, ParseErrors, ParseMode, LoadError, error::{load, parse_lines_checked}};
+
+pub struct Seat {
+    pub row: u16,
+    pub column: u16,
+}
+
+impl Seat {
+    pub fn from_code(code: &str) -> Result<Seat, ParseError> {
+        if code.len() != 10 || !code.is_ascii() {
+            return Err(ParseError::new(code, code, "expected a seat code of 10 characters"));
+        }
+
+        let mut row: u16 = 0;
+        let mut column: u16 = 0;
+
+        let mut row_partition: u16 = 64;
+        for (i, ch) in code[..7].char_indices() {
+            match ch {
+                'B' => row += row_partition,
+                'F' => {},
+                _ => return Err(ParseError::new(code, &code[i..i + 1], "expected 'F' or 'B'")),
+            }
+
+            row_partition >>= 1;
+        }
+
+        let mut column_partition: u16 = 4;
+        for (i, ch) in code[7..].char_indices() {
+            match ch {
+                'R' => column += column_partition,
+                'L' => {},
+                _ => return Err(ParseError::new(code, &code[7 + i..8 + i], "expected 'L' or 'R'")),
+            }
+
+            column_partition >>= 1;
+        }
+
+        return Ok(Seat { row, column });
+    }
+
+    pub fn seat_id(&self) -> u16 {
+        return 8*self.row + self.column;
+    }
+}
+
+// Returns the parsed seats together with the number of skipped lines.
+pub fn parse_seats(input: &str, mode: ParseMode) -> Result<(Vec<Seat>, usize), ParseErrors> {
+    return parse_lines_checked(input, mode, Seat::from_code);
+}
+
+pub fn load_seats(path: &Path, mode: ParseMode) -> Result<(Vec<Seat>, usize), LoadError> {
+    return load(path, |input| parse_seats(input, mode));
+}
+
+pub fn find_highest_seat_id(seats: &[Seat]) -> u16 {
+    return seats.iter().map(|seat| seat.seat_id()).max().unwrap_or(0);
+}
+
+pub fn find_id_of_missing_seat(seats: &[Seat]) -> u16 {
+    let seen_seat_ids: HashSet<u16> = HashSet::from_iter(
+        seats.iter().map(|seat| seat.seat_id())
+    );
+
+    for id in 1..(8*128 + 8) {
+        if !seen_seat_ids.contains(&id)
+                && seen_seat_ids.contains(&(id - 1))
+                && seen_seat_ids.contains(&(id + 1)) {
+            return id;
+        }
+    }
+
+    return 0;
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Seat>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Vec<Seat>, ParseErrors> {
+        return parse_seats(input, ParseMode::Strict).map(|(seats, _)| seats);
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Vec<Seat>, usize), ParseErrors> {
+        return parse_seats(input, ParseMode::Lenient);
+    }
+
+    fn part_one(seats: &Vec<Seat>) -> Option<u16> {
+        return seats.iter().map(|seat| seat.seat_id()).max();
+    }
+
+    fn part_two(seats: &Vec<Seat>) -> Option<u16> {
+        return match find_id_of_missing_seat(seats) {
+            0 => None,
+            id => Some(id)
+        };
+    }
+}
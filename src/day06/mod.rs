@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::load};
+
+#[derive(Clone)]
+pub struct AnswerSet {
+    set: u32,
+}
+
+impl AnswerSet {
+    fn add(&mut self, letter: char) {
+        if !letter.is_ascii_lowercase() {
+            panic!("Expected only lower case ascii letters as answers!");
+        }
+
+        self.set |= 0x1 << ((letter as u8) - b'a');
+    }
+
+    fn pair_union(&self, other: &AnswerSet) -> AnswerSet {
+        return AnswerSet { set: self.set | other.set };
+    }
+
+    pub fn many_union(answer_sets: &[AnswerSet]) -> AnswerSet {
+        let mut result = AnswerSet { set: 0 };
+
+        for answer_set in answer_sets {
+            result = result.pair_union(answer_set);
+        }
+
+        return result;
+    }
+
+    fn pair_intersect(&self, other: &AnswerSet) -> AnswerSet {
+        return AnswerSet { set: self.set & other.set };
+    }
+
+    pub fn many_intersect(answer_sets: &[AnswerSet]) -> AnswerSet {
+        if answer_sets.is_empty() {
+            return AnswerSet { set: 0 };
+        }
+
+        let mut result = answer_sets[0].clone();
+
+        for answer_set in answer_sets {
+            result = result.pair_intersect(answer_set);
+        }
+
+        return result;
+    }
+
+    pub fn count(&self) -> u8 {
+        let mut result = 0;
+
+        for i in 0..26 {
+            if self.set & (0x1 << i) != 0 {
+                result += 1;
+            }
+        }
+
+        return result;
+    }
+
+    pub fn from_answers(answers: &str) -> Result<AnswerSet, ParseError> {
+        let mut result = AnswerSet { set: 0 };
+
+        for (i, ch) in answers.char_indices() {
+            if !ch.is_ascii_lowercase() {
+                return Err(ParseError::new(
+                    answers, &answers[i..i + ch.len_utf8()], "expected a lower case ascii letter"
+                ));
+            }
+
+            result.add(ch);
+        }
+
+        return Ok(result);
+    }
+}
+
+// Groups are separated by blank lines, every line holds the answers of one person.
+pub fn parse_groups(input: &str) -> Result<Vec<Vec<AnswerSet>>, ParseError> {
+    let mut groups = Vec::new();
+    let mut current: Vec<AnswerSet> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if !current.is_empty() {
+                groups.push(current);
+                current = Vec::new();
+            }
+
+            continue;
+        }
+
+        current.push(AnswerSet::from_answers(line).map_err(|err| err.with_line(i + 1))?);
+    }
+
+    if !current.is_empty() {
+        groups.push(current);
+    }
+
+    return Ok(groups);
+}
+
+pub fn load_groups(path: &Path) -> Result<Vec<Vec<AnswerSet>>, LoadError> {
+    return load(path, parse_groups);
+}
+
+pub fn sum_union_counts(groups: &[Vec<AnswerSet>]) -> u32 {
+    return groups.iter()
+        .map(|group| AnswerSet::many_union(group))
+        .map(|answer| answer.count() as u32)
+        .sum::<u32>();
+}
+
+pub fn sum_intersect_counts(groups: &[Vec<AnswerSet>]) -> u32 {
+    return groups.iter()
+        .map(|group| AnswerSet::many_intersect(group))
+        .map(|answer| answer.count() as u32)
+        .sum::<u32>();
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<AnswerSet>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<AnswerSet>>, ParseErrors> {
+        return Ok(parse_groups(input)?);
+    }
+
+    fn part_one(groups: &Vec<Vec<AnswerSet>>) -> Option<u32> {
+        return Some(sum_union_counts(groups));
+    }
+
+    fn part_two(groups: &Vec<Vec<AnswerSet>>) -> Option<u32> {
+        return Some(sum_intersect_counts(groups));
+    }
+}
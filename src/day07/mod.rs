@@ -0,0 +1,138 @@
+use std::{path::Path, collections::{HashSet, HashMap}};
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::{load, parse_lines, parse_number}};
+
+pub struct BagRule {
+    container_bag: String,
+    children: Vec<(String, usize)>,
+}
+
+impl BagRule {
+    fn to_map(bags: &[BagRule]) -> HashMap<String, Vec<(String, usize)>> {
+        let mut result = HashMap::new();
+
+        for rule in bags {
+            result.insert(rule.container_bag.clone(), rule.children.clone());
+        }
+
+        return result;
+    }
+}
+
+fn strip_bags<'a>(line: &str, bag: &'a str, plural: bool) -> Result<&'a str, ParseError> {
+    let suffix = if plural { " bags" } else { " bag" };
+
+    return bag.strip_suffix(suffix).ok_or_else(|| ParseError::new(
+        line, bag, &format!("expected the bag to end with '{}'", suffix)
+    ));
+}
+
+fn parse_child_bag(line: &str, child_bag: &str) -> Result<(String, usize), ParseError> {
+    let split_child_bag: Vec<&str> = child_bag.splitn(2, ' ').collect();
+
+    if split_child_bag.len() != 2 {
+        return Err(ParseError::new(line, child_bag, "wrong format of a child bag - no space"));
+    }
+
+    let count = parse_number::<usize>(line, split_child_bag[0])?;
+    let name = strip_bags(line, split_child_bag[1], count != 1)?;
+
+    return Ok((name.to_string(), count));
+}
+
+pub fn parse_bag_rule(line: &str) -> Result<BagRule, ParseError> {
+    let split_line: Vec<&str> = line.split(" contain ").collect();
+
+    if split_line.len() != 2 {
+        return Err(ParseError::new(line, line, "wrong format of a bag rule - expected 'contain'"));
+    }
+
+    let container_bag = strip_bags(line, split_line[0], true)?.to_string();
+    let children = if split_line[1] == "no other bags." {
+        Vec::new()
+    } else {
+        split_line[1]
+            .strip_suffix('.')
+            .ok_or_else(|| ParseError::new(line, split_line[1], "expected the rule to end with '.'"))?
+            .split(", ")
+            .map(|child_bag| parse_child_bag(line, child_bag))
+            .collect::<Result<_, _>>()?
+    };
+
+    return Ok(BagRule { container_bag, children });
+}
+
+pub fn parse_rules(input: &str) -> Result<Vec<BagRule>, ParseError> {
+    return parse_lines(input, parse_bag_rule);
+}
+
+pub fn load_rules(path: &Path) -> Result<Vec<BagRule>, LoadError> {
+    return load(path, parse_rules);
+}
+
+pub fn count_all_that_can_recursively_contain(bags: &[BagRule], name: &str) -> usize {
+    let mut candidates: HashSet<String> = HashSet::new();
+    let mut previous_size: usize = 0;
+
+    candidates.insert(name.to_string());
+
+    while candidates.len() > previous_size {
+        previous_size = candidates.len();
+
+        for rule in bags {
+            if candidates.contains(&rule.container_bag) {
+                continue;
+            }
+
+            if rule.children.iter().map(|(name, _)| name).any(|name| candidates.contains(name)) {
+                candidates.insert(rule.container_bag.to_string());
+            }
+        }
+
+    }
+
+    return candidates.len() - 1;
+}
+
+pub fn all_contained_by(bags: &[BagRule], name: &str) -> usize {
+    let bag_map = BagRule::to_map(bags);
+
+    let mut total_count = 0;
+
+    let mut current_layer: Vec<(String, usize)> = vec![(name.to_string(), 1)];
+    let mut next_layer: Vec<(String, usize)> = Vec::new();
+
+    while !current_layer.is_empty() {
+        for (name, count) in current_layer {
+            for (child_name, child_count) in bag_map[&name].iter() {
+                next_layer.push((child_name.clone(), child_count * count));
+            }
+        }
+
+        total_count += next_layer.iter().map(|(_, count)| count).sum::<usize>();
+        current_layer = next_layer;
+        next_layer = Vec::new();
+    }
+
+    return total_count;
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<BagRule>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<BagRule>, ParseErrors> {
+        return Ok(parse_rules(input)?);
+    }
+
+    fn part_one(rules: &Vec<BagRule>) -> Option<usize> {
+        return Some(count_all_that_can_recursively_contain(rules, "shiny gold"));
+    }
+
+    fn part_two(rules: &Vec<BagRule>) -> Option<usize> {
+        return Some(all_contained_by(rules, "shiny gold"));
+    }
+}
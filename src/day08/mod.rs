@@ -0,0 +1,185 @@
+use std::{path::Path, collections::{HashSet, VecDeque}, str::FromStr};
+
+use crate::{Solution, ParseError, ParseErrors, ParseMode, LoadError, error::{load, parse_lines_checked, parse_number}};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operation {
+    Accumulate,
+    Jump,
+    NoOperation,
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "acc" => Ok(Operation::Accumulate),
+            "jmp" => Ok(Operation::Jump),
+            "nop" => Ok(Operation::NoOperation),
+            _ => Err(format!("Invalid Operation Name: {}", s))
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Instruction {
+    pub op: Operation,
+    pub arg: i32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split_str: Vec<&str> = s.split(' ').collect();
+
+        if split_str.len() != 2 {
+            return Err(ParseError::new(s, s, "the instruction is malformed"));
+        }
+
+        let op = Operation::from_str(split_str[0])
+            .map_err(|message| ParseError::new(s, split_str[0], &message))?;
+        let arg = parse_number::<i32>(s, split_str[1])?;
+
+        return Ok(Instruction { op, arg });
+    }
+}
+
+#[derive(Clone)]
+pub struct MachineState {
+    pub accumulator: i32,
+    pub program_counter: i32,
+}
+
+impl Default for MachineState {
+    fn default() -> MachineState {
+        return MachineState::new();
+    }
+}
+
+impl MachineState {
+    pub fn new() -> MachineState {
+        return MachineState { accumulator: 0, program_counter: 0 }
+    }
+
+    pub fn execute_instruction(&self, instruction: &Instruction) -> MachineState {
+        return match instruction.op {
+            Operation::Accumulate => MachineState {
+                accumulator: self.accumulator + instruction.arg,
+                program_counter: self.program_counter + 1,
+            },
+            Operation::Jump => MachineState {
+                accumulator: self.accumulator,
+                program_counter: self.program_counter + instruction.arg
+            },
+            Operation::NoOperation => MachineState {
+                accumulator: self.accumulator,
+                program_counter: self.program_counter + 1
+            }
+        }
+    }
+
+    pub fn run_program_until_loop(program: &[Instruction]) -> MachineState {
+        let mut seen_instructions: HashSet<i32> = HashSet::new();
+        let mut state = MachineState::new();
+
+        while !seen_instructions.contains(&state.program_counter) {
+            // println!("Current state: {:?}", state);
+            seen_instructions.insert(state.program_counter);
+            state = state.execute_instruction(&program[state.program_counter as usize]);
+        }
+
+        return state;
+    }
+
+    pub fn run_program_and_try_fixing(program: &[Instruction]) -> Option<MachineState> {
+        #[derive(Clone)]
+        struct PossibleState {
+            state: MachineState,
+            seen: HashSet<i32>,
+            flipped: bool,
+        }
+
+        let mut possible_states: VecDeque<PossibleState> = VecDeque::new();
+        let mut current_state = PossibleState {
+            state: MachineState::new(),
+            seen: HashSet::new(),
+            flipped: false,
+        };
+
+        while current_state.state.program_counter as isize != program.len() as isize {
+            if current_state.seen.contains(&current_state.state.program_counter) {
+                // this state is stuck in a loop - we can get rid of it
+                current_state = possible_states.pop_front()?;
+                continue;
+            }
+            current_state.seen.insert(current_state.state.program_counter);
+
+            let current_instruction: Instruction = program[
+                current_state.state.program_counter as usize
+            ];
+
+            if current_instruction.op == Operation::Accumulate || current_state.flipped {
+                current_state.state = current_state.state.execute_instruction(&current_instruction);
+                possible_states.push_back(current_state);
+                current_state = possible_states.pop_front().unwrap(); // the queue will never be
+                                                                      // empty here
+                continue;
+            }
+
+            let jump_instruction = Instruction { op: Operation::Jump, arg: current_instruction.arg };
+            let next_state_jump = PossibleState {
+                state: current_state.state.execute_instruction(&jump_instruction),
+                seen: current_state.seen.clone(),
+                flipped: current_instruction.op != Operation::Jump,
+            };
+
+            let nop_instruction = Instruction { op: Operation::NoOperation, arg: current_instruction.arg };
+            let next_state_nop = PossibleState {
+                state: current_state.state.execute_instruction(&nop_instruction),
+                seen: current_state.seen,
+                flipped: current_instruction.op != Operation::NoOperation,
+            };
+
+            possible_states.push_back(next_state_jump);
+            possible_states.push_back(next_state_nop);
+            current_state = possible_states.pop_front().unwrap();
+        }
+
+        return Some(current_state.state);
+    }
+}
+
+// Returns the parsed program together with the number of skipped lines.
+pub fn parse_instructions(input: &str, mode: ParseMode) -> Result<(Vec<Instruction>, usize), ParseErrors> {
+    return parse_lines_checked(input, mode, Instruction::from_str);
+}
+
+pub fn load_instructions(path: &Path, mode: ParseMode) -> Result<(Vec<Instruction>, usize), LoadError> {
+    return load(path, |input| parse_instructions(input, mode));
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseErrors> {
+        return parse_instructions(input, ParseMode::Strict).map(|(program, _)| program);
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Vec<Instruction>, usize), ParseErrors> {
+        return parse_instructions(input, ParseMode::Lenient);
+    }
+
+    fn part_one(program: &Vec<Instruction>) -> Option<i32> {
+        return Some(MachineState::run_program_until_loop(program).accumulator);
+    }
+
+    fn part_two(program: &Vec<Instruction>) -> Option<i32> {
+        return MachineState::run_program_and_try_fixing(program).map(|state| state.accumulator);
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::{load, parse_lines, parse_number}};
+
+fn is_sum_of_previous_n(nums: &[u64], index: usize, n: usize) -> bool {
+    for i in 1..n+1 {
+        for j in 1..i+1 {
+            if nums[index] == nums[index - i] + nums[index - j] {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+pub fn find_first_violating(nums: &[u64], n: usize) -> Option<u64> {
+    for i in n..nums.len() {
+        if !is_sum_of_previous_n(nums, i, n) {
+            return Some(nums[i]);
+        }
+    }
+
+    return None;
+}
+
+pub fn find_range_summing_to(nums: &[u64], total: u64) -> Option<&[u64]> {
+    for range_size in 2..nums.len() - 2 {
+        for i in 0..nums.len() - range_size {
+            if nums[i..i + range_size].iter().sum::<u64>() == total {
+                return Some(&nums[i..i + range_size]);
+            }
+        }
+    }
+
+    return None;
+}
+
+pub fn find_weakness(nums: &[u64], total: u64) -> Option<u64> {
+    let range = find_range_summing_to(nums, total)?;
+
+    return Some(range.iter().copied().min()? + range.iter().copied().max()?);
+}
+
+pub fn parse_numbers(input: &str) -> Result<Vec<u64>, ParseError> {
+    return parse_lines(input, |line| parse_number(line, line));
+}
+
+pub fn load_numbers(path: &Path) -> Result<Vec<u64>, LoadError> {
+    return load(path, parse_numbers);
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseErrors> {
+        return Ok(parse_numbers(input)?);
+    }
+
+    fn part_one(numbers: &Vec<u64>) -> Option<u64> {
+        return find_first_violating(numbers, 25);
+    }
+
+    fn part_two(numbers: &Vec<u64>) -> Option<u64> {
+        return find_weakness(numbers, find_first_violating(numbers, 25)?);
+    }
+}
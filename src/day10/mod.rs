@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use crate::{Solution, ParseError, ParseErrors, LoadError, error::{load, parse_lines, parse_number}};
+
+// the parameter must be a sorted vector, None when the adapters cannot all
+// be chained because two of them are equal or more than 3 jolts apart
+pub fn count_differences(joltage_ratings: &[u32]) -> Option<[u32; 3]> {
+    let mut jolt_differences: [u32; 3] = [0, 0, 1];
+    let mut last_rating = 0;
+
+    for rating in joltage_ratings {
+        let diff = rating - last_rating;
+        if !(1..=3).contains(&diff) {
+            return None;
+        }
+
+        jolt_differences[diff as usize - 1] += 1;
+        last_rating = *rating;
+    }
+
+    return Some(jolt_differences);
+}
+
+pub fn count_arrangements(joltage_ratings: &[u32]) -> u64 {
+    let mut previous_three: [(u32, u64); 3] = [(0, 0), (0, 0), (0, 1)];
+
+    for rating in joltage_ratings {
+        let rating_arrangements: u64 = previous_three.iter()
+            .filter(|(prev_rating, _)| rating - prev_rating < 4)
+            .map(|(_, arrangements)| arrangements)
+            .sum();
+
+        previous_three = [
+            previous_three[1],
+            previous_three[2],
+            (*rating, rating_arrangements)
+        ];
+    }
+
+    return previous_three[2].1;
+}
+
+pub fn parse_joltages(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut res: Vec<u32> = parse_lines(input, |line| parse_number(line, line))?;
+
+    res.sort();
+
+    return Ok(res);
+}
+
+pub fn load_joltages(path: &Path) -> Result<Vec<u32>, LoadError> {
+    return load(path, parse_joltages);
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseErrors> {
+        return Ok(parse_joltages(input)?);
+    }
+
+    fn part_one(ratings: &Vec<u32>) -> Option<u32> {
+        return count_differences(ratings).map(|diffs| diffs[0] * diffs[2]);
+    }
+
+    fn part_two(ratings: &Vec<u32>) -> Option<u64> {
+        return Some(count_arrangements(ratings));
+    }
+}
@@ -0,0 +1,183 @@
+use std::{ fmt, ops::Index, path::Path };
+
+use crate::{ Solution, ParseError, ParseErrors, LoadError, error::load, grid::{ Grid, DIRECTIONS } };
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum SeatType {
+    Floor,
+    Empty,
+    Occupied
+}
+
+impl SeatType {
+    fn from_string(s: char) -> Option<SeatType> {
+        return match s {
+            'L' => Some(SeatType::Empty),
+            '#' => Some(SeatType::Occupied),
+            '.' => Some(SeatType::Floor),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for SeatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", match self {
+            SeatType::Empty => 'L',
+            SeatType::Occupied => '#',
+            SeatType::Floor => '.',
+        });
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SeatMap {
+    seats: Grid<SeatType>,
+}
+
+impl SeatMap {
+    pub fn from_string(s: &str) -> Result<SeatMap, ParseError> {
+        let seats = Grid::parse(s, "expected 'L', '#' or '.'", SeatType::from_string)?;
+
+        return Ok(SeatMap { seats });
+    }
+
+    pub fn count_occpuied(&self) -> usize {
+        return self.seats
+            .iter()
+            .filter(|val| **val == SeatType::Occupied)
+            .count();
+    }
+
+    fn count_occupied_neighbors(&self, loc: (usize, usize)) -> usize {
+        return self.seats
+            .neighbours(loc.0, loc.1)
+            .filter(|neighbor| **neighbor == SeatType::Occupied)
+            .count();
+    }
+
+    fn run(&self, changed: &mut bool) -> SeatMap {
+        *changed = false;
+
+        let seats = self.seats.map(|loc, seat| match seat {
+            SeatType::Floor => SeatType::Floor,
+
+            SeatType::Occupied => if self.count_occupied_neighbors(loc) >= 4 {
+                *changed = true;
+                SeatType::Empty
+            } else {
+                SeatType::Occupied
+            },
+
+            SeatType::Empty => if self.count_occupied_neighbors(loc) == 0 {
+                *changed = true;
+                SeatType::Occupied
+            } else {
+                SeatType::Empty
+            }
+        });
+
+        return SeatMap { seats };
+    }
+
+    pub fn run_till_stable(&self) -> SeatMap {
+        let mut changed = true;
+        let mut current = self.run(&mut changed);
+
+        while changed {
+            current = current.run(&mut changed);
+        }
+
+        return current;
+    }
+
+    fn cast_vector(&self, from: (usize, usize), direction: (isize, isize)) -> Option<SeatType> {
+        return self.seats
+            .ray(from.0, from.1, direction)
+            .find(|seat| **seat != SeatType::Floor)
+            .copied();
+    }
+
+    fn count_far_occupied(&self, loc: (usize, usize)) -> usize {
+        return DIRECTIONS
+            .iter()
+            .map(|direction| self.cast_vector(loc, *direction))
+            .filter(|maybe_seat| *maybe_seat == Some(SeatType::Occupied))
+            .count();
+    }
+
+    fn run_far(&self, changed: &mut bool) -> SeatMap {
+        *changed = false;
+
+        let seats = self.seats.map(|loc, seat| match seat {
+            SeatType::Floor => SeatType::Floor,
+
+            SeatType::Occupied => if self.count_far_occupied(loc) >= 5 {
+                *changed = true;
+                SeatType::Empty
+            } else {
+                SeatType::Occupied
+            },
+
+            SeatType::Empty => if self.count_far_occupied(loc) == 0 {
+                *changed = true;
+                SeatType::Occupied
+            } else {
+                SeatType::Empty
+            }
+        });
+
+        return SeatMap { seats };
+    }
+
+    pub fn run_far_till_stable(&self) -> SeatMap {
+        let mut changed = true;
+        let mut current = self.run_far(&mut changed);
+
+        while changed {
+            current = current.run_far(&mut changed);
+        }
+
+        return current;
+
+    }
+
+}
+
+impl Index<(isize, isize)> for SeatMap {
+    type Output = SeatType;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        return self.seats.get(index.0, index.1).expect("Index out of bounds");
+    }
+}
+
+impl fmt::Display for SeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return self.seats.fmt(f);
+    }
+}
+
+pub fn read_map(path: &Path) -> Result<SeatMap, LoadError> {
+    return load(path, SeatMap::from_string);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SeatMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<SeatMap, ParseErrors> {
+        return Ok(SeatMap::from_string(input)?);
+    }
+
+    fn part_one(map: &SeatMap) -> Option<usize> {
+        return Some(map.run_till_stable().count_occpuied());
+    }
+
+    fn part_two(map: &SeatMap) -> Option<usize> {
+        return Some(map.run_far_till_stable().count_occpuied());
+    }
+}
@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use crate::{ Solution, ParseError, ParseErrors, LoadError, error::{ load, parse_lines, parse_number } };
+
+#[derive(Clone, Copy)]
+pub enum ShipAction {
+    Direction(i8, i8, u16),
+    Turn(u16),
+    Forward(u16),
+}
+
+impl ShipAction {
+    pub fn from_string(s: &str) -> Result<ShipAction, ParseError> {
+        let command_length = s.chars().next().map_or(0, char::len_utf8);
+        let (command, value) = s.split_at(command_length);
+        let parsed_value = parse_number::<u16>(s, value)?;
+
+        if (command == "L" || command == "R") && parsed_value % 90 != 0 {
+            return Err(ParseError::new(s, value, "the ship can only turn by multiples of 90 degrees"));
+        }
+
+        return match command {
+            "N" => Ok(ShipAction::Direction(0, 1, parsed_value)),
+            "S" => Ok(ShipAction::Direction(0, -1, parsed_value)),
+            "E" => Ok(ShipAction::Direction(1, 0, parsed_value)),
+            "W" => Ok(ShipAction::Direction(-1, 0, parsed_value)),
+            "L" => Ok(ShipAction::Turn(parsed_value % 360)),
+            "R" => Ok(ShipAction::Turn((360 - parsed_value % 360) % 360)),
+            "F" => Ok(ShipAction::Forward(parsed_value)),
+            _ => Err(ParseError::new(s, command, "expected one of N, S, E, W, L, R or F"))
+        }
+    }
+}
+
+pub struct ShipStatus {
+    direction: u16,
+    pub location: (i32, i32),
+}
+
+impl Default for ShipStatus {
+    fn default() -> ShipStatus {
+        return ShipStatus::new();
+    }
+}
+
+impl ShipStatus {
+    pub fn new() -> ShipStatus {
+        return ShipStatus { direction: 0, location: (0, 0) };
+    }
+
+    pub fn do_action(&self, action: ShipAction) -> ShipStatus {
+        // finally some good pattern matching 😩
+        return match action {
+            ShipAction::Direction(east, north, by) => ShipStatus {
+                direction: self.direction,
+                location: (self.location.0 + (by as i32)*(east as i32),
+                           self.location.1 + (by as i32)*(north as i32))
+            },
+
+            ShipAction::Turn(by) => ShipStatus {
+                direction: (self.direction + by) % 360,
+                location: self.location
+            },
+
+            ShipAction::Forward(by) => ShipStatus {
+                direction: self.direction,
+                location: match self.direction {
+                    0   => (self.location.0 + by as i32, self.location.1),
+                    90  => (self.location.0, self.location.1 + by as i32),
+                    180 => (self.location.0 - by as i32, self.location.1),
+                    270 => (self.location.0, self.location.1 - by as i32),
+                    _   => panic!("Invalid angle, going diagonally: {}!", self.direction)
+                }
+            }
+        }
+    }
+}
+
+pub struct ShipWaypointStatus {
+    pub waypoint: (i32, i32),
+    pub location: (i32, i32),
+}
+
+impl Default for ShipWaypointStatus {
+    fn default() -> ShipWaypointStatus {
+        return ShipWaypointStatus::new();
+    }
+}
+
+impl ShipWaypointStatus {
+    pub fn new() -> ShipWaypointStatus {
+        return ShipWaypointStatus { waypoint: (10, 1), location: (0, 0) }
+    }
+
+    pub fn do_action(&self, action: ShipAction) -> ShipWaypointStatus {
+        return match action {
+            ShipAction::Direction(east, north, by) => ShipWaypointStatus {
+                waypoint: (self.waypoint.0 + (by as i32)*(east as i32),
+                           self.waypoint.1 + (by as i32)*(north as i32)),
+                location: self.location
+            },
+
+            ShipAction::Turn(by) => ShipWaypointStatus {
+                waypoint: match by % 360 {
+                    0 => self.waypoint,
+                    90 => (-self.waypoint.1, self.waypoint.0),
+                    180 => (-self.waypoint.0, -self.waypoint.1),
+                    270 => (self.waypoint.1, -self.waypoint.0),
+                    _   => panic!("Invalid angle, going diagonally: {}!", by)
+                },
+                location: self.location
+            },
+
+            ShipAction::Forward(by) => ShipWaypointStatus {
+                waypoint: self.waypoint,
+                location: (self.location.0 + self.waypoint.0*(by as i32),
+                           self.location.1 + self.waypoint.1*(by as i32))
+            }
+        }
+
+    }
+}
+
+pub fn parse_actions(input: &str) -> Result<Vec<ShipAction>, ParseError> {
+    return parse_lines(input, ShipAction::from_string);
+}
+
+pub fn read_actions(path: &Path) -> Result<Vec<ShipAction>, LoadError> {
+    return load(path, parse_actions);
+}
+
+pub fn final_distance(actions: &[ShipAction]) -> i32 {
+    let final_status = actions.iter().fold(ShipStatus::new(), |status, action| status.do_action(*action));
+
+    return final_status.location.0.abs() + final_status.location.1.abs();
+}
+
+pub fn final_waypoint_distance(actions: &[ShipAction]) -> i32 {
+    let final_status = actions.iter().fold(
+        ShipWaypointStatus::new(), |status, action| status.do_action(*action)
+    );
+
+    return final_status.location.0.abs() + final_status.location.1.abs();
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<ShipAction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<ShipAction>, ParseErrors> {
+        return Ok(parse_actions(input)?);
+    }
+
+    fn part_one(actions: &Vec<ShipAction>) -> Option<i32> {
+        return Some(final_distance(actions));
+    }
+
+    fn part_two(actions: &Vec<ShipAction>) -> Option<i32> {
+        return Some(final_waypoint_distance(actions));
+    }
+}
@@ -0,0 +1,168 @@
+use std::{ path::Path, cmp::{ max, min } };
+
+use crate::{ Solution, ParseError, ParseErrors, LoadError, error::{ load, parse_number } };
+
+
+pub fn find_closest_gt_multiple(to: u32, of: u32) -> u32 {
+    return of - to % of;
+}
+
+fn bus_line(input: &str) -> Result<&str, ParseError> {
+    return input
+        .lines()
+        .nth(1)
+        .ok_or_else(|| ParseError::new("", "", "expected a second line with the bus ids").with_line(2));
+}
+
+fn parse_bus(line: &str, symbol: &str) -> Result<u32, ParseError> {
+    let bus = parse_number::<u32>(line, symbol).map_err(|err| err.with_line(2))?;
+
+    if bus == 0 {
+        return Err(ParseError::new(line, symbol, "bus ids have to be positive").with_line(2));
+    }
+
+    return Ok(bus);
+}
+
+pub fn parse_bus_info_task1(input: &str) -> Result<(u32, Vec<u32>), ParseError> {
+    let first_line = input.lines().next().unwrap_or("");
+    let departure_timestamp = parse_number(first_line, first_line).map_err(|err| err.with_line(1))?;
+    let line = bus_line(input)?;
+
+    return Ok((
+        departure_timestamp,
+        line
+            .split(',')
+            .filter(|symbol| *symbol != "x")
+            .map(|symbol| parse_bus(line, symbol))
+            .collect::<Result<_, _>>()?
+    ));
+}
+
+pub fn load_bus_info_task1(path: &Path) -> Result<(u32, Vec<u32>), LoadError> {
+    return load(path, parse_bus_info_task1);
+}
+
+pub fn find_best_bus(departure_timestamp: u32, buses: &[u32]) -> Option<(u32, u32)> {
+    return buses.iter()
+        .map(|bus| (*bus, find_closest_gt_multiple(departure_timestamp, *bus)))
+        .min_by(|(_, d1), (_, d2)| d1.cmp(d2));
+}
+
+fn find_bezout_coefs(p: i128, q: i128) -> (i128, i128) {
+    let (mut old_rem, mut new_rem) = (max(p, q), min(p, q));
+    let (mut old_bcoef, mut new_bcoef) = (1, 0);
+    let (mut old_smcoef, mut new_smcoef) = (0, 1);
+
+    while new_rem != 0 {
+        let quotient = old_rem / new_rem;
+        (old_rem, new_rem) = (new_rem, old_rem - new_rem*quotient);
+        (old_bcoef, new_bcoef) = (new_bcoef, old_bcoef - new_bcoef*quotient);
+        (old_smcoef, new_smcoef) = (new_smcoef, old_smcoef - new_smcoef*quotient);
+    }
+
+    return if p > q {
+        (old_bcoef, old_smcoef)
+    } else {
+        (old_smcoef, old_bcoef)
+    };
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub modulo: i128,
+    pub value: i128
+}
+
+impl Congruence {
+    pub fn new(modulo: i128, value: i128) -> Congruence {
+        return Congruence {
+            modulo,
+            value: if value < 0 {
+                modulo + (value % modulo)
+            } else {
+                value % modulo
+            }
+        };
+    }
+}
+
+pub fn solve_congruence_system(x: Congruence, y: Congruence) -> Congruence {
+    let (x_bezout, _) = find_bezout_coefs(x.modulo, y.modulo);
+    let modulo = x.modulo * y.modulo;
+    return Congruence::new(
+        modulo,
+        x.value + (y.value - x.value) * x.modulo * x_bezout,
+    );
+}
+
+pub fn solve_all(congruences: &[Congruence]) -> Congruence {
+    return congruences.iter().fold(
+        Congruence { modulo: 1, value: 0 },
+        |x, y| solve_congruence_system(x, *y)
+    );
+}
+
+pub fn parse_bus_info_task2(input: &str) -> Result<Vec<Congruence>, ParseError> {
+    let line = bus_line(input)?;
+    let mut congruences = Vec::new();
+
+    for (i, symbol) in line.split(',').enumerate() {
+        if symbol == "x" {
+            continue;
+        }
+
+        let congruence = Congruence::new(parse_bus(line, symbol)? as i128, -(i as i128));
+
+        if congruence.modulo != 1 {
+            congruences.push(congruence);
+        }
+    }
+
+    return Ok(congruences);
+}
+
+pub fn load_bus_info_task2(path: &Path) -> Result<Vec<Congruence>, LoadError> {
+    return load(path, parse_bus_info_task2);
+}
+
+pub struct BusNotes {
+    pub departure_timestamp: u32,
+    pub buses: Vec<u32>,
+    pub congruences: Vec<Congruence>,
+}
+
+pub fn parse_bus_notes(input: &str) -> Result<BusNotes, ParseError> {
+    let (departure_timestamp, buses) = parse_bus_info_task1(input)?;
+
+    return Ok(BusNotes {
+        departure_timestamp,
+        buses,
+        congruences: parse_bus_info_task2(input)?,
+    });
+}
+
+pub fn load_bus_notes(path: &Path) -> Result<BusNotes, LoadError> {
+    return load(path, parse_bus_notes);
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = BusNotes;
+    type Answer1 = u32;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<BusNotes, ParseErrors> {
+        return Ok(parse_bus_notes(input)?);
+    }
+
+    fn part_one(notes: &BusNotes) -> Option<u32> {
+        return find_best_bus(notes.departure_timestamp, &notes.buses)
+            .map(|(bus, wait)| bus * wait);
+    }
+
+    fn part_two(notes: &BusNotes) -> Option<i128> {
+        return Some(solve_all(&notes.congruences).value);
+    }
+}
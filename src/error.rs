@@ -0,0 +1,268 @@
+use std::{fmt, io::{self, BufRead}, path::{Path, PathBuf}, process::exit, str::FromStr};
+
+use crate::input::{read_input, display_name};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // `text` is expected to be a slice of `line_text` - its position
+    // within the line gives us the column of the error
+    pub fn new(line_text: &str, text: &str, message: &str) -> ParseError {
+        let line_start = line_text.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line_start)
+            .filter(|offset| offset + text.len() <= line_text.len())
+            .or_else(|| line_text.find(text))
+            .unwrap_or(0);
+
+        return ParseError {
+            file: None,
+            line: 0,
+            column: line_text[..offset].chars().count() + 1,
+            text: text.to_string(),
+            line_text: line_text.to_string(),
+            message: message.to_string(),
+        };
+    }
+
+    pub fn with_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        return self;
+    }
+
+    pub fn with_file(mut self, path: &Path) -> ParseError {
+        self.file = Some(path.to_path_buf());
+        return self;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = match &self.file {
+            Some(path) => display_name(path),
+            None => String::from("<input>"),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        return write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), underline);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Every line rejected while parsing, so that they can all be reported at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseErrors {
+    pub errors: Vec<ParseError>,
+    // rejected lines which were only counted, not kept
+    pub omitted: usize,
+}
+
+impl ParseErrors {
+    pub fn with_file(self, path: &Path) -> ParseErrors {
+        return ParseErrors {
+            errors: self.errors.into_iter().map(|error| error.with_file(path)).collect(),
+            omitted: self.omitted,
+        };
+    }
+}
+
+impl From<ParseError> for ParseErrors {
+    fn from(error: ParseError) -> ParseErrors {
+        return ParseErrors { errors: vec![error], omitted: 0 };
+    }
+}
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f, "\n")?;
+            }
+
+            error.fmt(f)?;
+        }
+
+        let rejected = self.errors.len() + self.omitted;
+
+        if rejected > 1 {
+            write!(f, "\n\nerror: {} lines were rejected", rejected)?;
+        }
+
+        if self.omitted > 0 {
+            write!(f, ", only the first {} are shown", self.errors.len())?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::error::Error for ParseErrors {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseMode {
+    // malformed lines make the whole input invalid
+    Strict,
+    // malformed lines are skipped and only counted
+    Lenient,
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io { path: PathBuf, error: io::Error },
+    Parse(ParseErrors),
+}
+
+impl LoadError {
+    // Prints the diagnostic and terminates - meant for the binaries.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self);
+        exit(1);
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            LoadError::Io { path, error } => write!(f, "error: could not read {}: {}", display_name(path), error),
+            LoadError::Parse(error) => error.fmt(f),
+        };
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<ParseError> for LoadError {
+    fn from(error: ParseError) -> LoadError {
+        return LoadError::Parse(error.into());
+    }
+}
+
+impl From<ParseErrors> for LoadError {
+    fn from(errors: ParseErrors) -> LoadError {
+        return LoadError::Parse(errors);
+    }
+}
+
+pub fn load<T, E, F>(path: &Path, parse: F) -> Result<T, LoadError>
+where E: Into<ParseErrors>, F: FnOnce(&str) -> Result<T, E> {
+    let input = read_input(path).map_err(|error| LoadError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+
+    return parse(&input).map_err(|error| LoadError::Parse(error.into().with_file(path)));
+}
+
+// Parses every non-empty line, numbering the errors by their line.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where F: FnMut(&str) -> Result<T, ParseError> {
+    return input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(line).map_err(|error| error.with_line(i + 1)))
+        .collect();
+}
+
+// Like `parse_lines`, but goes on after a malformed line. In strict mode all the
+// rejected lines are returned as errors, in lenient mode they are only counted.
+pub fn parse_lines_checked<T, F>(input: &str, mode: ParseMode, mut parse_line: F) -> Result<(Vec<T>, usize), ParseErrors>
+where F: FnMut(&str) -> Result<T, ParseError> {
+    return parse_numbered_lines_checked(input, mode, |_, line| parse_line(line));
+}
+
+// Like `parse_lines_checked`, but also passes the number of every line.
+pub fn parse_numbered_lines_checked<T, F>(input: &str, mode: ParseMode, mut parse_line: F) -> Result<(Vec<T>, usize), ParseErrors>
+where F: FnMut(usize, &str) -> Result<T, ParseError> {
+    let mut values = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_line(i + 1, line) {
+            Ok(value) => values.push(value),
+            Err(error) => rejected.push(error.with_line(i + 1)),
+        }
+    }
+
+    if mode == ParseMode::Strict && !rejected.is_empty() {
+        return Err(ParseErrors { errors: rejected, omitted: 0 });
+    }
+
+    return Ok((values, rejected.len()));
+}
+
+pub fn parse_number<T: FromStr>(line_text: &str, text: &str) -> Result<T, ParseError> {
+    return text
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line_text, text, "expected a number"));
+}
+
+// The most rejected lines `stream_lines_checked` keeps, the rest are counted.
+pub const MAX_STREAMED_ERRORS: usize = 20;
+
+// Like `parse_numbered_lines_checked`, but reads the lines one by one, so that
+// only the current line is held in memory. The path is only used in messages.
+pub fn stream_lines_checked<R, F>(mut reader: R, path: &Path, mode: ParseMode, mut parse_line: F) -> Result<usize, LoadError>
+where R: BufRead, F: FnMut(usize, &str) -> Result<(), ParseError> {
+    let mut line = String::new();
+    let mut rejected = Vec::new();
+    let mut skipped = 0;
+    let mut number = 0;
+
+    loop {
+        line.clear();
+
+        let read = reader.read_line(&mut line).map_err(|error| LoadError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+        if read == 0 {
+            break;
+        }
+
+        number += 1;
+
+        // the same line endings as `str::lines`
+        let text = line.strip_suffix('\n').map(|text| text.strip_suffix('\r').unwrap_or(text)).unwrap_or(&line);
+
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = parse_line(number, text) {
+            skipped += 1;
+
+            // lenient mode keeps nothing of the rejected lines, strict mode
+            // only the first few
+            if mode == ParseMode::Strict && rejected.len() < MAX_STREAMED_ERRORS {
+                rejected.push(error.with_line(number).with_file(path));
+            }
+        }
+    }
+
+    if !rejected.is_empty() {
+        let omitted = skipped - rejected.len();
+        return Err(LoadError::Parse(ParseErrors { errors: rejected, omitted }));
+    }
+
+    return Ok(skipped);
+}
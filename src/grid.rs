@@ -0,0 +1,165 @@
+use std::{fmt, ops::Index};
+
+use crate::{ParseError, error::parse_lines};
+
+// The eight directions around a cell as (x, y) steps, row by row.
+pub const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+// A rectangle of cells stored row by row, `x` goes to the right and `y` down.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        if cells.len() != width * height {
+            panic!("A {}x{} grid cannot hold {} cells", width, height, cells.len());
+        }
+
+        return Grid { width, height, cells };
+    }
+
+    // Every non-empty line is a row and every character a cell. `parse_cell`
+    // returns None for the characters which are not allowed, those are
+    // reported with `expected` as the message.
+    pub fn parse<F>(input: &str, expected: &str, mut parse_cell: F) -> Result<Grid<T>, ParseError>
+    where F: FnMut(char) -> Option<T> {
+        let mut width: Option<usize> = None;
+
+        let rows = parse_lines(input, |line| {
+            let mut row = Vec::new();
+
+            for (i, ch) in line.char_indices() {
+                match parse_cell(ch) {
+                    Some(cell) => row.push(cell),
+                    None => return Err(ParseError::new(line, &line[i..i + ch.len_utf8()], expected)),
+                }
+            }
+
+            let expected_width = *width.get_or_insert(row.len());
+
+            if row.len() != expected_width {
+                return Err(ParseError::new(line, line, &format!(
+                    "row has width {}, expected {}", row.len(), expected_width
+                )));
+            }
+
+            return Ok(row);
+        })?;
+
+        return Ok(Grid {
+            width: width.unwrap_or(0),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+
+        return Some(&self.cells[y as usize * self.width + x as usize]);
+    }
+
+    // Indexes the grid as if it was repeated infinitely in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        return &self.cells[y * self.width + x];
+    }
+
+    // The cells around the given one, leaving out those beyond the edges.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = &T> + '_ {
+        return DIRECTIONS
+            .iter()
+            .filter_map(move |(dx, dy)| self.get(x as isize + dx, y as isize + dy));
+    }
+
+    // The cells seen from the given one when looking in `direction`, up to the edge.
+    pub fn ray(&self, x: usize, y: usize, direction: (isize, isize)) -> impl Iterator<Item = &T> + '_ {
+        let (dx, dy) = direction;
+
+        return (1..).map_while(move |step| self.get(x as isize + dx * step, y as isize + dy * step));
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        return (0..self.height).map(move |y| self.row(y));
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        return (0..self.height).map(move |y| &self[(x, y)]);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        return (0..self.width).map(move |x| self.column(x));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        return self.cells.iter();
+    }
+
+    // The positions of all the cells in the order they are stored.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where F: FnMut((usize, usize), &T) -> U {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.positions().zip(self.cells.iter()).map(|(position, cell)| f(position, cell)).collect(),
+        };
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        if x >= self.width || y >= self.height {
+            panic!("Position ({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        }
+
+        return &self.cells[y * self.width + x];
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,58 @@
+use std::{io::{self, BufRead, BufReader, Read, stdin}, fs::{File, read_to_string}, path::Path, process::exit};
+
+use crate::ParseMode;
+
+// Standing in for a path, `-` means the standard input.
+pub const STDIN_PATH: &str = "-";
+
+// The input path given on the command line, which defaults to the standard input.
+pub fn input_path(arg: Option<&str>) -> &Path {
+    return Path::new(arg.unwrap_or(STDIN_PATH));
+}
+
+// Prints what is wrong with the arguments and how to use the binary, then
+// terminates - meant for the binaries like `LoadError::exit`.
+pub fn usage_error(usage: &str, message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, usage);
+    exit(2);
+}
+
+// Tells how many malformed lines were skipped, strict mode never skips any.
+pub fn report_skipped(mode: ParseMode, skipped: usize) {
+    if mode == ParseMode::Lenient {
+        eprintln!("Skipped {} malformed lines", skipped);
+    }
+}
+
+pub fn is_stdin(path: &Path) -> bool {
+    return path == Path::new(STDIN_PATH);
+}
+
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if !is_stdin(path) {
+        return read_to_string(path);
+    }
+
+    let mut input = String::new();
+    stdin().read_to_string(&mut input)?;
+
+    return Ok(input);
+}
+
+// For reading the input a line at a time instead of all at once.
+pub fn open_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if !is_stdin(path) {
+        return Ok(Box::new(BufReader::new(File::open(path)?)));
+    }
+
+    return Ok(Box::new(stdin().lock()));
+}
+
+// How the input is referred to in messages.
+pub fn display_name(path: &Path) -> String {
+    if is_stdin(path) {
+        return String::from("<stdin>");
+    }
+
+    return path.display().to_string();
+}
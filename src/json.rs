@@ -0,0 +1,47 @@
+// Just enough JSON writing for the machine readable outputs, so that
+// the crate does not need any dependencies.
+
+pub fn string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+
+    result.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => result.push(ch),
+        }
+    }
+
+    result.push('"');
+
+    return result;
+}
+
+// Answers which look like integers are written as numbers, anything else as a string.
+pub fn answer(value: &Option<String>) -> String {
+    return match value {
+        None => String::from("null"),
+        Some(value) if value.parse::<i128>().is_ok() => value.clone(),
+        Some(value) => string(value),
+    };
+}
+
+// The values have to be JSON already.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let members: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", string(key), value))
+        .collect();
+
+    return format!("{{{}}}", members.join(","));
+}
+
+pub fn array(values: &[String]) -> String {
+    return format!("[{}]", values.join(","));
+}
@@ -0,0 +1,25 @@
+pub mod error;
+pub mod input;
+pub mod json;
+pub mod csv;
+pub mod grid;
+pub mod solution;
+pub mod runner;
+pub mod answers;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+pub use solution::Solution;
+pub use error::{ParseError, ParseErrors, ParseMode, LoadError};
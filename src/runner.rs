@@ -0,0 +1,151 @@
+use std::{fmt::Display, hint::black_box, time::{Duration, Instant}};
+
+use crate::{
+    Solution, ParseErrors, ParseMode,
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05,
+    day06::Day06, day07::Day07, day08::Day08, day09::Day09, day10::Day10,
+    day11::Day11, day12::Day12, day13::Day13,
+};
+
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    // why a part has no answer, when it failed instead of having none
+    pub part1_error: Option<String>,
+    pub part2_error: Option<String>,
+    // malformed lines skipped in lenient mode
+    pub skipped: usize,
+    // parsing and both parts together
+    pub elapsed: Duration,
+}
+
+fn stringify<T: Display>(answer: Result<Option<T>, String>) -> (Option<String>, Option<String>) {
+    return match answer {
+        Ok(answer) => (answer.map(|a| a.to_string()), None),
+        Err(error) => (None, Some(error)),
+    };
+}
+
+fn solve<S: Solution>(input: &str, mode: ParseMode) -> Result<Answers, ParseErrors> {
+    let start = Instant::now();
+
+    let (parsed, skipped) = match mode {
+        ParseMode::Strict => (S::parse(input)?, 0),
+        ParseMode::Lenient => S::parse_lenient(input)?,
+    };
+    let (part1, part1_error) = stringify(S::try_part_one(&parsed));
+    let (part2, part2_error) = stringify(S::try_part_two(&parsed));
+
+    return Ok(Answers { part1, part2, part1_error, part2_error, skipped, elapsed: start.elapsed() });
+}
+
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    // expects at least one measurement
+    fn from_measurements(mut measurements: Vec<Duration>) -> Timings {
+        measurements.sort();
+
+        let middle = measurements.len() / 2;
+        let median = if measurements.len().is_multiple_of(2) {
+            (measurements[middle - 1] + measurements[middle]) / 2
+        } else {
+            measurements[middle]
+        };
+
+        return Timings {
+            min: measurements[0],
+            median,
+            max: measurements[measurements.len() - 1],
+        };
+    }
+}
+
+pub struct Benchmark {
+    pub runs: usize,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+fn measure<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Timings {
+    let mut measurements = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        black_box(f());
+        measurements.push(start.elapsed());
+    }
+
+    return Timings::from_measurements(measurements);
+}
+
+fn bench<S: Solution>(input: &str, mode: ParseMode, runs: usize) -> Result<Benchmark, ParseErrors> {
+    let runs = runs.max(1);
+    let parse = || match mode {
+        ParseMode::Strict => S::parse(input).map(|parsed| (parsed, 0)),
+        ParseMode::Lenient => S::parse_lenient(input),
+    };
+
+    // a failing input is reported once instead of being measured
+    let (parsed, _) = parse()?;
+
+    return Ok(Benchmark {
+        runs,
+        parse: measure(runs, parse),
+        part1: measure(runs, || S::part_one(&parsed)),
+        part2: measure(runs, || S::part_two(&parsed)),
+    });
+}
+
+// A registered day with its answers erased to strings, so that all
+// the days can be stored and driven uniformly.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    solver: fn(&str, ParseMode) -> Result<Answers, ParseErrors>,
+    benchmarker: fn(&str, ParseMode, usize) -> Result<Benchmark, ParseErrors>,
+}
+
+impl Day {
+    pub fn solve(&self, input: &str, mode: ParseMode) -> Result<Answers, ParseErrors> {
+        return (self.solver)(input, mode);
+    }
+
+    // Runs every stage `runs` times on its own.
+    pub fn bench(&self, input: &str, mode: ParseMode, runs: usize) -> Result<Benchmark, ParseErrors> {
+        return (self.benchmarker)(input, mode, runs);
+    }
+
+    pub fn input_file_name(&self) -> String {
+        return format!("day{:02}.txt", self.number);
+    }
+}
+
+const fn day<S: Solution>(number: u8, title: &'static str) -> Day {
+    return Day { number, title, solver: solve::<S>, benchmarker: bench::<S> };
+}
+
+pub const DAYS: [Day; 13] = [
+    day::<Day01>(1, "Report Repair"),
+    day::<Day02>(2, "Password Philosophy"),
+    day::<Day03>(3, "Toboggan Trajectory"),
+    day::<Day04>(4, "Passport Processing"),
+    day::<Day05>(5, "Binary Boarding"),
+    day::<Day06>(6, "Custom Customs"),
+    day::<Day07>(7, "Handy Haversacks"),
+    day::<Day08>(8, "Handheld Halting"),
+    day::<Day09>(9, "Encoding Error"),
+    day::<Day10>(10, "Adapter Array"),
+    day::<Day11>(11, "Seating System"),
+    day::<Day12>(12, "Rain Risk"),
+    day::<Day13>(13, "Shuttle Search"),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    return DAYS.iter().find(|day| day.number == number);
+}
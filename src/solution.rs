@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::error::ParseErrors;
+
+// A puzzle solution split into its three stages, so that the solvers can be
+// driven from code instead of only through the day binaries.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseErrors>;
+
+    // Skips malformed entries instead of rejecting the whole input and
+    // returns how many were skipped. Most days have nothing to skip.
+    fn parse_lenient(input: &str) -> Result<(Self::Input, usize), ParseErrors> {
+        return Self::parse(input).map(|parsed| (parsed, 0));
+    }
+
+    // None means the puzzle has no answer for the given input
+    fn part_one(input: &Self::Input) -> Option<Self::Answer1>;
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2>;
+
+    // Like the parts, but an answer which cannot be worked out, like a
+    // product which overflows, is an error instead of no answer. Most days
+    // cannot fail.
+    fn try_part_one(input: &Self::Input) -> Result<Option<Self::Answer1>, String> {
+        return Ok(Self::part_one(input));
+    }
+
+    fn try_part_two(input: &Self::Input) -> Result<Option<Self::Answer2>, String> {
+        return Ok(Self::part_two(input));
+    }
+}
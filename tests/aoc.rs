@@ -0,0 +1,67 @@
+// Runs the binaries themselves, for what only shows in their output and exit code.
+
+use std::{
+    env::temp_dir, fs::{create_dir_all, remove_dir_all, write}, io::Write, path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+fn inputs_dir(name: &str) -> PathBuf {
+    let dir = temp_dir().join(format!("aoc20-{}-{}", name, std::process::id()));
+
+    let _ = remove_dir_all(&dir);
+    create_dir_all(&dir).unwrap();
+
+    return dir;
+}
+
+fn run_all(dir: &Path) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc")).arg("all").arg(dir).output().unwrap();
+}
+
+#[test]
+fn skips_only_the_missing_inputs() {
+    let dir = inputs_dir("missing");
+    let output = run_all(&dir);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Day 01: skipped"), "{}", stdout);
+
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fails_on_inputs_which_cannot_be_read() {
+    let dir = inputs_dir("unreadable");
+    write(dir.join("day01.txt"), b"1721\n\xff\xfe\n").unwrap();
+
+    let output = run_all(&dir);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!stdout.contains("Day 01: skipped"), "{}", stdout);
+    assert!(stderr.contains("error: could not read"), "{}", stderr);
+    assert!(stderr.contains("day01.txt"), "{}", stderr);
+    // the other days still run
+    assert!(stdout.contains("Day 13: skipped"), "{}", stdout);
+
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn answers_day13_without_any_bus() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day13"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"5\nx,x\n").unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("There is no bus to take"), "{}", stdout);
+}
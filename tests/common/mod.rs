@@ -0,0 +1,44 @@
+// Helpers shared by the tests, each of them only uses some.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+pub fn example(name: &str) -> PathBuf {
+    return [env!("CARGO_MANIFEST_DIR"), "tests", "examples", name].iter().collect();
+}
+
+// A small xorshift generator, so that the random cases are the same on every run.
+pub struct Random(pub u64);
+
+impl Random {
+    pub fn next(&mut self, bound: u32) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return (self.0 % bound as u64) as u32;
+    }
+
+    // Expenses for day 1. Half of the cases only have non-negative numbers,
+    // the rest mix in refunds.
+    pub fn numbers(&mut self, max_len: u32, bound: u32) -> Vec<i64> {
+        let len = self.next(max_len);
+        let offset = if self.next(2) == 0 { 0 } else { bound as i64 / 2 };
+
+        return (0..len).map(|_| self.next(bound) as i64 - offset).collect();
+    }
+
+    pub fn total(&mut self, bound: u32) -> i64 {
+        return self.next(bound) as i64 - bound as i64 / 4;
+    }
+
+    // A grid of trees for day 3.
+    pub fn forest(&mut self, max_width: u32, max_height: u32) -> String {
+        let width = 1 + self.next(max_width);
+        let height = 1 + self.next(max_height);
+        let density = 1 + self.next(4);
+
+        return (0..height)
+            .map(|_| (0..width).map(|_| if self.next(5) < density { '#' } else { '.' }).collect::<String>() + "\n")
+            .collect();
+    }
+}
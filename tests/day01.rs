@@ -0,0 +1,230 @@
+use std::collections::BTreeSet;
+
+use aoc20::{
+    ParseMode,
+    runner::find_day,
+    day01::{
+        read_numbers, find_summing_to, combinations_summing_to, checked_product, ProductOverflow,
+        subset_sum::{count_subsets_summing_to, smallest_subset_summing_to, SubsetSumError},
+    },
+};
+
+mod common;
+
+use common::{example, Random};
+
+// Tries every combination of positions.
+fn brute_force(nums: &[i64], count: u32, total: i64) -> bool {
+    if count == 0 {
+        return total == 0;
+    }
+
+    return (0..nums.len()).any(|i| brute_force(&nums[i + 1..], count - 1, total - nums[i]));
+}
+
+fn is_taken_from(found: &[i64], nums: &[i64]) -> bool {
+    let mut left = nums.to_vec();
+
+    for num in found {
+        match left.iter().position(|n| n == num) {
+            Some(i) => left.swap_remove(i),
+            None => return false,
+        };
+    }
+
+    return true;
+}
+
+#[test]
+fn does_not_reuse_a_number_after_skipping_a_bigger_one() {
+    assert_eq!(find_summing_to(&[3000, 1010], 2, 2020), None);
+    assert_eq!(find_summing_to(&[3000, 1010, 1010], 2, 2020), Some(vec![1010, 1010]));
+    assert_eq!(find_summing_to(&[5000, 4000, 1, 2, 3], 3, 6), Some(vec![1, 2, 3]));
+}
+
+#[test]
+fn handles_the_edge_counts() {
+    assert_eq!(find_summing_to(&[1, 2], 0, 0), Some(vec![]));
+    assert_eq!(find_summing_to(&[1, 2], 0, 3), None);
+    assert_eq!(find_summing_to(&[1, 2], 1, 2), Some(vec![2]));
+    assert_eq!(find_summing_to(&[1, 2], 3, 3), None);
+    assert_eq!(find_summing_to::<u32>(&[], 2, 0), None);
+    assert_eq!(find_summing_to(&[0, 0, 0, 0, 0], 5, 0), Some(vec![0; 5]));
+}
+
+#[test]
+fn supports_refunds_and_wide_amounts() {
+    assert_eq!(find_summing_to(&[3000, -980, 5], 2, 2020), Some(vec![-980, 3000]));
+    assert_eq!(find_summing_to(&[i32::MAX, i32::MAX, -1], 2, -2), None);
+    assert_eq!(find_summing_to(&[u64::MAX, 1, u64::MAX - 1], 2, u64::MAX), Some(vec![1, u64::MAX - 1]));
+    assert_eq!(find_summing_to(&[i64::MIN, i64::MAX, 1], 3, 0), Some(vec![i64::MIN, 1, i64::MAX]));
+    assert_eq!(find_summing_to::<u8>(&[200, 100, 55], 2, 255), Some(vec![55, 200]));
+}
+
+#[test]
+fn reports_products_which_overflow() {
+    assert_eq!(checked_product(&[1721u32, 299]), Ok(514579));
+    assert_eq!(checked_product::<i64>(&[-3, 5]), Ok(-15));
+    assert_eq!(checked_product::<i64>(&[]), Ok(1));
+
+    let overflow = checked_product(&[100_000u32, 100_000]).unwrap_err();
+    assert_eq!(overflow, ProductOverflow { numbers: vec![100_000, 100_000] });
+    assert_eq!(overflow.to_string(), "the product of 100000, 100000 does not fit into u32");
+}
+
+#[test]
+fn runner_reports_products_which_overflow() {
+    let answers = find_day(1).unwrap().solve("4000000000000\n-3999999997980\n", ParseMode::Strict).unwrap();
+
+    assert_eq!(answers.part1, None);
+    assert_eq!(
+        answers.part1_error.as_deref(),
+        Some("the product of -3999999997980, 4000000000000 does not fit into i64")
+    );
+    // no three entries sum to 2020, which is no answer rather than an error
+    assert_eq!((answers.part2, answers.part2_error), (None, None));
+}
+
+#[test]
+fn reads_numbers_as_any_width() {
+    let nums: Vec<u32> = read_numbers(&example("day01.txt")).unwrap();
+    assert_eq!(find_summing_to(&nums, 2, 2020), Some(vec![299, 1721]));
+
+    let nums: Vec<i64> = read_numbers(&example("day01.txt")).unwrap();
+    assert_eq!(find_summing_to(&nums, 3, 2020), Some(vec![366, 675, 979]));
+
+    assert!(read_numbers::<u8>(&example("day01.txt")).is_err());
+}
+
+#[test]
+fn agrees_with_brute_force() {
+    let mut random = Random(0x2020_1201);
+
+    for _ in 0..5000 {
+        let nums = random.numbers(12, 40);
+        let count = random.next(6);
+        let total = random.total(80);
+
+        let found = find_summing_to(&nums, count, total);
+        let case = format!("{:?}, count {}, total {}", nums, count, total);
+
+        assert_eq!(found.is_some(), brute_force(&nums, count, total), "{}", case);
+
+        if let Some(found) = found {
+            assert_eq!(found.len(), count as usize, "{}", case);
+            assert_eq!(found.iter().sum::<i64>(), total, "{}", case);
+            assert!(is_taken_from(&found, &nums), "{}", case);
+        }
+    }
+}
+
+// Every distinct multiset of values picked from `count` different positions.
+fn brute_force_all(nums: &[i64], count: u32, total: i64) -> BTreeSet<Vec<i64>> {
+    fn go(nums: &[i64], count: u32, total: i64, picked: &mut Vec<i64>, found: &mut BTreeSet<Vec<i64>>) {
+        if count == 0 {
+            if total == 0 {
+                let mut combination = picked.clone();
+                combination.sort_unstable();
+                found.insert(combination);
+            }
+
+            return;
+        }
+
+        for i in 0..nums.len() {
+            picked.push(nums[i]);
+            go(&nums[i + 1..], count - 1, total - nums[i], picked, found);
+            picked.pop();
+        }
+    }
+
+    let mut found = BTreeSet::new();
+    go(nums, count, total, &mut Vec::new(), &mut found);
+
+    return found;
+}
+
+#[test]
+fn lists_every_combination_once() {
+    let all: Vec<Vec<u32>> = combinations_summing_to(&[1, 5, 3, 3, 2, 4, 9], 2, 6).collect();
+    assert_eq!(all, vec![vec![1, 5], vec![2, 4], vec![3, 3]]);
+
+    let all: Vec<Vec<u32>> = combinations_summing_to(&[3, 3], 2, 6).collect();
+    assert_eq!(all, vec![vec![3, 3]]);
+
+    let all: Vec<Vec<i32>> = combinations_summing_to(&[-2, 8, 4, 2, 10], 2, 6).collect();
+    assert_eq!(all, vec![vec![-2, 8], vec![2, 4]]);
+
+    assert_eq!(combinations_summing_to(&[3], 2, 6).count(), 0);
+    assert_eq!(combinations_summing_to(&[1, 2], 0, 0).collect::<Vec<_>>(), vec![Vec::<u32>::new()]);
+}
+
+#[test]
+fn all_combinations_agree_with_brute_force() {
+    let mut random = Random(0x1721_0979);
+
+    for _ in 0..3000 {
+        let nums = random.numbers(12, 20);
+        let count = random.next(5);
+        let total = random.total(40);
+
+        let found: Vec<Vec<i64>> = combinations_summing_to(&nums, count, total).collect();
+        let expected: Vec<Vec<i64>> = brute_force_all(&nums, count, total).into_iter().collect();
+
+        assert_eq!(found, expected, "{:?}, count {}, total {}", nums, count, total);
+    }
+}
+
+// The sizes of all the subsets summing to `total`, by trying every one of them.
+fn brute_force_subsets(nums: &[i64], total: i64) -> Vec<usize> {
+    return (0..1u32 << nums.len())
+        .filter(|mask| (0..nums.len()).filter(|i| mask & 1 << i != 0).map(|i| nums[i]).sum::<i64>() == total)
+        .map(|mask| mask.count_ones() as usize)
+        .collect();
+}
+
+#[test]
+fn counts_subsets_of_any_size() {
+    let nums: Vec<u32> = read_numbers(&example("day01.txt")).unwrap();
+
+    assert_eq!(count_subsets_summing_to(&nums, 2020), Ok(2));
+    assert_eq!(smallest_subset_summing_to(&nums, 2020), Ok(Some(vec![1721, 299])));
+    assert_eq!(count_subsets_summing_to(&[0, 0, 5], 5), Ok(4));
+    assert_eq!(count_subsets_summing_to(&[1, 2], 0), Ok(1));
+    assert_eq!(count_subsets_summing_to(&[1, -1, 2, -2], 0), Ok(4));
+    assert_eq!(smallest_subset_summing_to(&[1, 2], 0), Ok(Some(vec![])));
+    assert_eq!(smallest_subset_summing_to(&[1, 2], 4), Ok(None));
+    assert_eq!(smallest_subset_summing_to(&[2030, 1000, -10, 1020], 2020), Ok(Some(vec![2030, -10])));
+}
+
+#[test]
+fn reports_subset_tables_which_do_not_fit() {
+    assert_eq!(count_subsets_summing_to(&[0; 127], 0), Ok(1 << 127));
+    assert_eq!(count_subsets_summing_to(&[0; 128], 0), Err(SubsetSumError::CountOverflow));
+
+    let error = count_subsets_summing_to(&[5_000_000_000i64, -5_000_000_000], 0).unwrap_err();
+    assert_eq!(error, SubsetSumError::TableTooLarge { entries: 10_000_000_001 });
+}
+
+#[test]
+fn subsets_agree_with_brute_force() {
+    let mut random = Random(0x0299_0366);
+
+    for _ in 0..2000 {
+        let nums = random.numbers(12, 25);
+        let total = random.total(60);
+        let case = format!("{:?}, total {}", nums, total);
+
+        let sizes = brute_force_subsets(&nums, total);
+        assert_eq!(count_subsets_summing_to(&nums, total), Ok(sizes.len() as u128), "{}", case);
+
+        match smallest_subset_summing_to(&nums, total).unwrap() {
+            None => assert!(sizes.is_empty(), "{}", case),
+            Some(subset) => {
+                assert_eq!(Some(&subset.len()), sizes.iter().min(), "{}", case);
+                assert_eq!(subset.iter().sum::<i64>(), total, "{}", case);
+                assert!(is_taken_from(&subset, &nums), "{}", case);
+            }
+        }
+    }
+}
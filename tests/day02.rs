@@ -0,0 +1,153 @@
+use std::{io::BufReader, path::Path};
+
+use aoc20::{
+    ParseMode, LoadError, error::MAX_STREAMED_ERRORS,
+    day02::{
+        parse_lines, count_valid_streaming,
+        policy::{PasswordPolicy, POLICIES, SledPolicy, TobogganPolicy, find_policy, count_valid, count_valid_by_policy},
+        expression::parse_policy_expression,
+        report::{build_report, csv_header, csv_row},
+    },
+};
+
+const PASSWORDS: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+1-2 x: xx
+2-3 q: qqz
+";
+
+#[test]
+fn finds_policies_by_name() {
+    assert_eq!(find_policy("sled").map(|policy| policy.name()), Some("sled"));
+    assert_eq!(find_policy("toboggan").map(|policy| policy.name()), Some("toboggan"));
+    assert!(find_policy("shopkeeper").is_none());
+}
+
+#[test]
+fn counts_every_policy_in_one_pass() {
+    let (lines, _) = parse_lines(PASSWORDS, ParseMode::Strict).unwrap();
+
+    assert_eq!(count_valid(&lines, &SledPolicy), 4);
+    assert_eq!(count_valid(&lines, &TobogganPolicy), 2);
+    assert_eq!(count_valid_by_policy(&lines, &POLICIES[..2]), vec![("sled", 4), ("toboggan", 2)]);
+    assert_eq!(count_valid_by_policy(&lines, &[&TobogganPolicy]), vec![("toboggan", 2)]);
+}
+
+#[test]
+fn evaluates_policy_expressions() {
+    let (lines, _) = parse_lines(PASSWORDS, ParseMode::Strict).unwrap();
+    let count = |source: &str| count_valid(&lines, &parse_policy_expression(source).unwrap());
+
+    assert_eq!(count("sled"), 4);
+    assert_eq!(count("sled AND NOT toboggan"), 2);
+    assert_eq!(count("(at-low OR at-high) AND NOT (at-low AND at-high)"), count("toboggan"));
+    // NOT binds tighter than AND, which binds tighter than OR
+    assert_eq!(count("not sled or at-low and not at-high"), count("(NOT sled) OR (at-low AND (NOT at-high))"));
+    assert_eq!(count("NOT NOT sled"), 4);
+    assert_eq!(parse_policy_expression("  sled and toboggan ").unwrap().name(), "sled and toboggan");
+}
+
+#[test]
+fn points_at_errors_in_policy_expressions() {
+    let error = |source: &str| {
+        let err = parse_policy_expression(source).err().unwrap();
+        (err.column, err.message)
+    };
+
+    assert_eq!(error("sled AND nope"), (10, String::from("unknown policy, expected one of sled, toboggan, at-low, at-high")));
+    assert_eq!(error("(sled OR toboggan"), (18, String::from("expected ')'")));
+    assert_eq!(error("sled toboggan"), (6, String::from("expected AND, OR or the end of the expression")));
+    assert_eq!(error("sled AND OR toboggan"), (10, String::from("expected a policy, NOT or '('")));
+    assert_eq!(error(""), (1, String::from("expected a policy, NOT or '('")));
+}
+
+#[test]
+fn handles_long_passwords_and_wide_chars() {
+    let long = format!("300-400 a: {}\n1-299 b: {}b\n", "a".repeat(350), "a".repeat(298));
+    let (lines, _) = parse_lines(&long, ParseMode::Strict).unwrap();
+
+    assert_eq!(count_valid(&lines, &SledPolicy), 2);
+    assert_eq!(count_valid(&lines, &TobogganPolicy), 2);
+
+    // positions are counted in chars, not bytes
+    let wide = "1-3 é: éaé\n2-3 ö: éöa\n2-4 ß: ßßßß\n";
+    let (lines, _) = parse_lines(wide, ParseMode::Strict).unwrap();
+
+    assert_eq!(count_valid(&lines, &SledPolicy), 2);
+    assert_eq!(count_valid(&lines, &TobogganPolicy), 1);
+}
+
+#[test]
+fn checks_positions_in_any_order() {
+    let (lines, _) = parse_lines("0-2 a: ba\n3-1 a: aba\n3-1 a: abb\n4-9 a: aa\n", ParseMode::Strict).unwrap();
+    let accepted: Vec<bool> = lines.iter().map(|(rule, word)| TobogganPolicy.accepts(rule, word)).collect();
+
+    assert_eq!(accepted, vec![true, false, true, false]);
+}
+
+#[test]
+fn reports_every_line_with_its_number() {
+    let input = "1-3 a: abcde\n\n1-3 b: cdefg\nnot a rule\n2-9 c: c,\"cc\n";
+    let policies: [&dyn PasswordPolicy; 2] = [&SledPolicy, &TobogganPolicy];
+
+    assert!(build_report(input, ParseMode::Strict, &policies).is_err());
+
+    let (reports, skipped) = build_report(input, ParseMode::Lenient, &policies).unwrap();
+    assert_eq!(skipped, 1);
+    assert_eq!(reports.iter().map(|report| report.line).collect::<Vec<_>>(), vec![1, 3, 5]);
+    assert_eq!(reports[2].letter_count, 3);
+    assert_eq!(reports[2].positions, vec![1, 4, 5]);
+    assert_eq!(reports[2].verdicts, vec![("sled", true), ("toboggan", false)]);
+
+    assert_eq!(csv_header(&policies), "line,low,high,letter,password,count,positions,sled,toboggan");
+    assert_eq!(csv_row(&reports[1]), "3,1,3,b,cdefg,0,,rejected,rejected");
+    assert_eq!(csv_row(&reports[2]), "5,2,9,c,\"c,\"\"cc\",3,1 4 5,accepted,rejected");
+    let (reports, _) = build_report("1-3 =: =HYPERLINK(\"x\")\n1-3 -: -a\n", ParseMode::Strict, &policies).unwrap();
+    assert_eq!(csv_row(&reports[0]), "1,1,3,'=,\"'=HYPERLINK(\"\"x\"\")\",1,1,accepted,accepted");
+    assert_eq!(csv_row(&reports[1]), "2,1,3,'-,'-a,1,1,accepted,accepted");
+
+    let (reports, _) = build_report(input, ParseMode::Lenient, &policies).unwrap();
+    assert_eq!(
+        reports[0].to_string(),
+        "line 1: 1-3 a: abcde\n    count of 'a': 1, at positions 1\n    sled: accepted, toboggan: accepted"
+    );
+}
+
+#[test]
+fn streaming_counts_agree_with_the_parsed_lines() {
+    let input = "1-3 a: abcde\r\n\n1-3 b: cdefg\r\nnot a rule\n2-9 c: ccccccccc\n1-2 x: xx\n2-3 q: qqz";
+    let path = Path::new("passwords.txt");
+
+    let (lines, skipped) = parse_lines(input, ParseMode::Lenient).unwrap();
+    let expected = count_valid_by_policy(&lines, &POLICIES);
+
+    // a tiny buffer makes the lines span several reads
+    let reader = BufReader::with_capacity(3, input.as_bytes());
+    let streamed = count_valid_streaming(reader, path, ParseMode::Lenient, &POLICIES).unwrap();
+    assert_eq!(streamed, (expected, skipped));
+
+    let error = count_valid_streaming(input.as_bytes(), path, ParseMode::Strict, &POLICIES).unwrap_err();
+    assert!(error.to_string().contains("passwords.txt:4:1"), "{}", error);
+
+    let (strict, _) = count_valid_streaming(PASSWORDS.as_bytes(), path, ParseMode::Strict, &[&SledPolicy]).unwrap();
+    assert_eq!(strict, vec![("sled", 4)]);
+}
+
+#[test]
+fn keeps_only_the_first_rejected_lines_while_streaming() {
+    let input = "not a rule\n".repeat(MAX_STREAMED_ERRORS * 50);
+    let path = Path::new("passwords.txt");
+
+    match count_valid_streaming(input.as_bytes(), path, ParseMode::Strict, &POLICIES) {
+        Err(LoadError::Parse(errors)) => {
+            assert_eq!(errors.errors.len(), MAX_STREAMED_ERRORS);
+            assert_eq!(errors.omitted, MAX_STREAMED_ERRORS * 49);
+            assert!(errors.to_string().ends_with(&format!(
+                "{} lines were rejected, only the first {} are shown", MAX_STREAMED_ERRORS * 50, MAX_STREAMED_ERRORS
+            )));
+        }
+        _ => panic!("expected the lines to be rejected"),
+    }
+}
@@ -0,0 +1,152 @@
+use aoc20::day03::{
+    read_grid, parse_grid, count_trees, count_trees_with_edge, count_trees_for_slopes, rank_slopes, route,
+    Edge, Position, Slope,
+    render::{render_route, render_svg},
+};
+
+mod common;
+
+use common::{example, Random};
+
+#[test]
+fn ranks_every_slope_in_the_ranges() {
+    let grid = read_grid(&example("day03.txt")).unwrap();
+    let ranked = rank_slopes(&grid, 0..=3, 0..=2, Edge::Wrap);
+
+    // the slopes with y = 0 are left out
+    assert_eq!(ranked.len(), 8);
+    assert_eq!(ranked.first(), Some(&(Slope { x: 0, y: 2 }, 1)));
+    assert_eq!(ranked.last(), Some(&(Slope { x: 3, y: 1 }, 7)));
+    assert!(ranked.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+    for (slope, trees) in ranked.iter() {
+        assert_eq!(count_trees(&grid, slope), *trees, "{:?}", slope);
+    }
+
+    assert!(rank_slopes(&grid, 1..=7, 0..=0, Edge::Wrap).is_empty());
+}
+
+#[test]
+fn reduces_fractional_slopes_to_whole_steps() {
+    assert_eq!(Slope::from_fractions((3, 1), (1, 1)), Some(Slope { x: 3, y: 1 }));
+    assert_eq!(Slope::from_fractions((1, 2), (1, 1)), Some(Slope { x: 1, y: 2 }));
+    assert_eq!(Slope::from_fractions((6, 4), (1, 2)), Some(Slope { x: 3, y: 1 }));
+    assert_eq!(Slope::from_fractions((-2, 4), (3, 1)), Some(Slope { x: -1, y: 6 }));
+    assert_eq!(Slope::from_fractions((0, 5), (2, 3)), Some(Slope { x: 0, y: 2 }));
+    assert_eq!(Slope::from_fractions((1, 0), (1, 1)), None);
+    assert_eq!(Slope::from_fractions((isize::MAX, 1), (1, 2)), None);
+}
+
+#[test]
+fn follows_the_edge_policy() {
+    let grid = parse_grid("#..\n..#\n.#.\n#..\n..#\n").unwrap();
+    let left = Slope { x: -1, y: 1 };
+
+    // going left wraps around to the last column
+    assert_eq!(count_trees(&grid, &left), 5);
+    assert_eq!(count_trees_with_edge(&grid, &left, Edge::Stop), 1);
+    // the columns 0, 0, 1, 2, 2 - the edge column is met twice when bouncing
+    assert_eq!(count_trees_with_edge(&grid, &left, Edge::Reflect), 3);
+    // the columns 0, 2, 1, 0, 2
+    assert_eq!(count_trees_with_edge(&grid, &Slope { x: 2, y: 1 }, Edge::Reflect), 5);
+
+    assert_eq!(grid.tree_at(&Position { x: -3, y: 0 }, Edge::Wrap), Some(true));
+    assert_eq!(grid.tree_at(&Position { x: 3, y: 0 }, Edge::Stop), None);
+    assert_eq!(grid.tree_at(&Position { x: 0, y: 5 }, Edge::Wrap), None);
+    assert!(grid[&Position { x: -1, y: 4 }]);
+}
+
+#[test]
+fn keeps_going_with_huge_steps() {
+    let grid = parse_grid("###\n###\n###\n###\n").unwrap();
+
+    for x in [isize::MAX, isize::MIN] {
+        assert_eq!(count_trees_with_edge(&grid, &Slope { x, y: 1 }, Edge::Wrap), 4);
+        assert_eq!(count_trees_with_edge(&grid, &Slope { x, y: 1 }, Edge::Reflect), 4);
+        assert_eq!(count_trees_with_edge(&grid, &Slope { x, y: 1 }, Edge::Stop), 1);
+    }
+
+    // the positions are folded back into the grid
+    let columns = |edge| route(&grid, Slope { x: isize::MAX, y: 1 }, edge).map(|(p, _)| p.x).collect::<Vec<_>>();
+    assert_eq!(columns(Edge::Wrap), vec![0, 1, 2, 0]);
+    assert_eq!(columns(Edge::Reflect), vec![0, 1, 2, 2]);
+}
+
+#[test]
+#[should_panic]
+fn panics_right_below_the_grid() {
+    let grid = parse_grid("#..\n").unwrap();
+    let _ = grid[&Position { x: 0, y: 1 }];
+}
+
+#[test]
+fn draws_the_routes() {
+    let grid = parse_grid("#..\n..#\n.#.\n").unwrap();
+
+    // the copies of the grid go as far as the route
+    let route = render_route(&grid, &Slope { x: 2, y: 1 }, Edge::Wrap);
+    assert_eq!(route.to_string(), "X..#..\n..X..#\n.#..X.");
+
+    let route = render_route(&grid, &Slope { x: -1, y: 1 }, Edge::Wrap);
+    assert_eq!(route.to_string(), "#..X..\n..X..#\n.X..#.");
+
+    let route = render_route(&grid, &Slope { x: -1, y: 1 }, Edge::Stop);
+    assert_eq!(route.to_string(), "X..\n..#\n.#.");
+
+    let svg = render_svg(&grid, &[Slope { x: 2, y: 1 }, Slope { x: 1, y: 2 }], Edge::Wrap);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"60\" height=\"30\""));
+    assert!(svg.contains("<title>right 2, down 1: 3 trees</title>"));
+    assert!(svg.contains("<title>right 1, down 2: 2 trees</title>"));
+    assert!(svg.contains("<polyline points=\"5,5 25,15 45,25\""));
+    assert_eq!(svg.matches("<circle").count(), 5);
+}
+
+#[test]
+fn folds_routes_too_wide_to_draw() {
+    let grid = parse_grid("#..\n..#\n.#.\n").unwrap();
+
+    let route = render_route(&grid, &Slope { x: 3001, y: 1 }, Edge::Wrap);
+    assert_eq!(route.to_string(), "X..\n.O#\n.#O");
+
+    let route = render_route(&grid, &Slope { x: 3002, y: 1 }, Edge::Reflect);
+    assert_eq!(route.to_string(), "X..\n..X\n.X.");
+
+    let route = render_route(&grid, &Slope { x: isize::MIN, y: 1 }, Edge::Wrap);
+    assert_eq!(route.width(), 3);
+
+    // a folded route is only drawn as dots
+    let svg = render_svg(&grid, &[Slope { x: isize::MAX, y: 1 }, Slope { x: 1, y: 1 }], Edge::Wrap);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"30\""));
+    assert!(!svg.contains("<polyline"));
+}
+
+#[test]
+fn counts_many_slopes_like_one_at_a_time() {
+    let mut random = Random(0x0323_0031);
+
+    // wider than a word, so that the rows take several of them
+    for _ in 0..200 {
+        let grid = parse_grid(&random.forest(150, 60)).unwrap();
+        let slopes: Vec<Slope> = (0..20)
+            .map(|_| Slope { x: random.next(401) as isize - 200, y: random.next(8) as usize })
+            .chain([Slope { x: isize::MAX, y: 1 }, Slope { x: isize::MIN, y: 1 }])
+            .collect();
+
+        for edge in [Edge::Wrap, Edge::Stop, Edge::Reflect] {
+            let expected: Vec<u32> = slopes.iter().map(|slope| count_trees_with_edge(&grid, slope, edge)).collect();
+            assert_eq!(count_trees_for_slopes(&grid, &slopes, edge), expected, "{:?}\n{}", edge, grid);
+        }
+    }
+
+    // a route through nothing but trees meets one in every row, however far it goes
+    for height in [1, 2, 7, 60] {
+        let grid = parse_grid(&format!("{}\n", "#".repeat(150)).repeat(height)).unwrap();
+        let slopes = [Slope { x: isize::MAX, y: 1 }, Slope { x: isize::MIN, y: 1 }];
+
+        for edge in [Edge::Wrap, Edge::Reflect] {
+            assert_eq!(count_trees_for_slopes(&grid, &slopes, edge), vec![height as u32; 2], "{:?}", edge);
+        }
+
+        assert_eq!(count_trees_for_slopes(&grid, &slopes, Edge::Stop), vec![1, 1]);
+    }
+}
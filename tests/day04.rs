@@ -0,0 +1,110 @@
+use aoc20::day04::{
+    parse_records, count_accepted,
+    schema::{Schema, Validator, FieldRule, UnitRange, parse_schema},
+    report::check_records,
+};
+
+fn validator(schema: &Schema, key: &str) -> Validator {
+    return schema.fields.iter().find(|rule| rule.key == key).unwrap().validator.clone();
+}
+
+#[test]
+fn built_in_schema_validates_like_the_puzzle() {
+    let schema = Schema::default();
+
+    // the examples of the second part of the puzzle
+    let cases = [
+        ("byr", "2002", true), ("byr", "2003", false),
+        ("hgt", "60in", true), ("hgt", "190cm", true), ("hgt", "190in", false), ("hgt", "190", false),
+        ("hcl", "#123abc", true), ("hcl", "#123abz", false), ("hcl", "123abc", false),
+        ("ecl", "brn", true), ("ecl", "wat", false),
+        ("pid", "000000001", true), ("pid", "0123456789", false),
+    ];
+
+    for (key, value, valid) in cases.iter() {
+        assert_eq!(validator(&schema, key).accepts(value), *valid, "{}:{}", key, value);
+    }
+
+    assert!(!schema.fields.iter().find(|rule| rule.key == "cid").unwrap().required);
+}
+
+#[test]
+fn reads_a_custom_schema() {
+    let schema = parse_schema("\
+# only the birth year and the height matter here
+byr required year 1900-2100
+
+hgt required height 100-250cm 40-100in
+nick optional
+").unwrap();
+
+    assert_eq!(schema.fields, vec![
+        FieldRule { key: "byr".to_string(), required: true, validator: Validator::Year { min: 1900, max: 2100 } },
+        FieldRule {
+            key: "hgt".to_string(),
+            required: true,
+            validator: Validator::Height(vec![
+                UnitRange { unit: "cm".to_string(), min: 100, max: 250 },
+                UnitRange { unit: "in".to_string(), min: 40, max: 100 },
+            ]),
+        },
+        FieldRule { key: "nick".to_string(), required: false, validator: Validator::Any },
+    ]);
+    assert_eq!(schema.fields[1].validator.to_string(), "height 100-250cm 40-100in");
+
+    let records = parse_records("byr:1910 hgt:99in\n\nbyr:1910 hgt:101in\n\nhgt:150cm\n").unwrap();
+    assert_eq!(count_accepted(&records, &schema, false), 2);
+    assert_eq!(count_accepted(&records, &schema, true), 1);
+}
+
+#[test]
+fn points_at_mistakes_in_the_schema() {
+    let cases = [
+        ("byr required year 1920", 1, 19, "expected a range in the form min-max"),
+        ("byr required year 2002-1920", 1, 19, "the range ends before it starts"),
+        ("hgt required height 150-193", 1, 21, "expected a range with a unit, like 150-193cm"),
+        ("pid required digits", 1, 14, "digits takes a single argument"),
+        ("hcl required colour red", 1, 21, "colour takes no arguments"),
+        ("ecl maybe", 1, 5, "expected required or optional"),
+        ("byr required\n\nbyr optional", 3, 1, "the field is already described above"),
+    ];
+
+    for (input, line, column, message) in cases.iter() {
+        let error = parse_schema(input).unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (*line, *column, *message), "{}", input);
+    }
+}
+
+#[test]
+fn explains_why_passports_fail() {
+    let records = parse_records("\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+hgt:190in ecl:wat iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f cid:88
+
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f byr:1900
+").unwrap();
+    let reports = check_records(&Schema::default(), &records);
+
+    assert_eq!(reports.iter().map(|report| (report.index, report.line)).collect::<Vec<_>>(), vec![(1, 1), (2, 4), (3, 8)]);
+    assert!(reports[0].is_valid());
+
+    assert_eq!(reports[1].missing, vec!["pid"]);
+    assert_eq!(reports[1].to_string(), "\
+passport 2 (line 4): invalid
+    missing pid
+    hgt:190in out of 59-76in
+    ecl:wat expected one of amb, blu, brn, gry, grn, hzl, oth");
+
+    // the last birth year is the one which counts
+    assert_eq!(reports[2].to_json(), concat!(
+        r#"{"index":3,"line":8,"valid":false,"missing":[],"invalid":"#,
+        r#"[{"key":"byr","value":"1900","rule":"year 1920-2002","reason":"out of 1920-2002"}]}"#,
+    ));
+
+    let valid = reports.iter().filter(|report| report.is_valid()).count();
+    assert_eq!(valid, count_accepted(&records, &Schema::default(), true));
+}
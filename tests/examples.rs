@@ -0,0 +1,166 @@
+// The worked examples from the puzzle statements, fed through the loaders and
+// the solvers of every day.
+
+use aoc20::{
+    ParseMode, runner,
+    day01, day02, day03, day04, day05, day06, day07,
+    day08, day09, day10, day11, day12, day13,
+};
+
+mod common;
+
+use common::example;
+
+#[test]
+fn day01_report_repair() {
+    let numbers: Vec<i64> = day01::read_numbers(&example("day01.txt")).unwrap();
+
+    let pair = day01::find_summing_to(&numbers, 2, 2020).unwrap();
+    assert_eq!(day01::checked_product(&pair), Ok(514579));
+
+    let triple = day01::find_summing_to(&numbers, 3, 2020).unwrap();
+    assert_eq!(day01::checked_product(&triple), Ok(241861950));
+}
+
+#[test]
+fn day02_password_philosophy() {
+    let (lines, skipped) = day02::read_lines(&example("day02.txt"), ParseMode::Strict).unwrap();
+
+    assert_eq!(skipped, 0);
+    assert_eq!(day02::policy::count_valid(&lines, &day02::policy::SledPolicy), 2);
+    assert_eq!(day02::policy::count_valid(&lines, &day02::policy::TobogganPolicy), 1);
+}
+
+#[test]
+fn day03_toboggan_trajectory() {
+    let grid = day03::read_grid(&example("day03.txt")).unwrap();
+
+    assert_eq!(day03::count_trees(&grid, &day03::Slope { x: 3, y: 1 }), 7);
+
+    let counts: Vec<u32> = day03::interesting_slopes()
+        .iter()
+        .map(|slope| day03::count_trees(&grid, slope))
+        .collect();
+    assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+    assert_eq!(counts.iter().product::<u32>(), 336);
+}
+
+#[test]
+fn day04_passport_processing() {
+    let records = day04::load_records(&example("day04.txt")).unwrap();
+    assert_eq!(day04::count_valid_records(&records, false), 2);
+
+    let invalid = day04::load_records(&example("day04-invalid.txt")).unwrap();
+    assert_eq!(day04::count_valid_records(&invalid, true), 0);
+
+    let valid = day04::load_records(&example("day04-valid.txt")).unwrap();
+    assert_eq!(day04::count_valid_records(&valid, true), 4);
+}
+
+#[test]
+fn day05_binary_boarding() {
+    let (seats, _) = day05::load_seats(&example("day05.txt"), ParseMode::Strict).unwrap();
+
+    let ids: Vec<u16> = seats.iter().map(|seat| seat.seat_id()).collect();
+    assert_eq!(ids, vec![357, 567, 119, 820]);
+    assert_eq!(day05::find_highest_seat_id(&seats), 820);
+
+    // the statement has no example for the second part - seats 566 and 568
+    // are taken, so the one between them is ours
+    let (neighbours, _) = day05::parse_seats("BFFFBBFRRL\nBFFFBBBLLL\n", ParseMode::Strict).unwrap();
+    assert_eq!(day05::find_id_of_missing_seat(&neighbours), 567);
+}
+
+#[test]
+fn day06_custom_customs() {
+    let groups = day06::load_groups(&example("day06.txt")).unwrap();
+
+    assert_eq!(day06::sum_union_counts(&groups), 11);
+    assert_eq!(day06::sum_intersect_counts(&groups), 6);
+}
+
+#[test]
+fn day07_handy_haversacks() {
+    let rules = day07::load_rules(&example("day07.txt")).unwrap();
+
+    assert_eq!(day07::count_all_that_can_recursively_contain(&rules, "shiny gold"), 4);
+    assert_eq!(day07::all_contained_by(&rules, "shiny gold"), 32);
+
+    let nested = day07::load_rules(&example("day07-nested.txt")).unwrap();
+    assert_eq!(day07::all_contained_by(&nested, "shiny gold"), 126);
+}
+
+#[test]
+fn day08_handheld_halting() {
+    let (program, _) = day08::load_instructions(&example("day08.txt"), ParseMode::Strict).unwrap();
+
+    assert_eq!(day08::MachineState::run_program_until_loop(&program).accumulator, 5);
+    assert_eq!(day08::MachineState::run_program_and_try_fixing(&program).unwrap().accumulator, 8);
+}
+
+#[test]
+fn day09_encoding_error() {
+    let numbers = day09::load_numbers(&example("day09.txt")).unwrap();
+
+    // the example uses a preamble of 5 numbers instead of 25
+    let violating = day09::find_first_violating(&numbers, 5).unwrap();
+    assert_eq!(violating, 127);
+    assert_eq!(day09::find_range_summing_to(&numbers, violating), Some(&[15, 25, 47, 40][..]));
+    assert_eq!(day09::find_weakness(&numbers, violating), Some(62));
+}
+
+#[test]
+fn day10_adapter_array() {
+    let ratings = day10::load_joltages(&example("day10.txt")).unwrap();
+
+    assert_eq!(day10::count_differences(&ratings), Some([7, 0, 5]));
+    assert_eq!(day10::count_arrangements(&ratings), 8);
+
+    let larger = day10::load_joltages(&example("day10-larger.txt")).unwrap();
+
+    assert_eq!(day10::count_differences(&larger), Some([22, 0, 10]));
+    assert_eq!(day10::count_arrangements(&larger), 19208);
+
+    // adapters which cannot be chained have no answer instead of panicking
+    assert_eq!(day10::count_differences(&[1, 1, 2]), None);
+    let answers = runner::find_day(10).unwrap().solve("1\n10\n", ParseMode::Strict).unwrap();
+    assert_eq!((answers.part1, answers.part2), (None, Some(String::from("0"))));
+}
+
+#[test]
+fn day11_seating_system() {
+    let map = day11::read_map(&example("day11.txt")).unwrap();
+
+    assert_eq!(map.run_till_stable().count_occpuied(), 37);
+    assert_eq!(map.run_far_till_stable().count_occpuied(), 26);
+}
+
+#[test]
+fn day12_rain_risk() {
+    let actions = day12::read_actions(&example("day12.txt")).unwrap();
+
+    assert_eq!(day12::final_distance(&actions), 25);
+    assert_eq!(day12::final_waypoint_distance(&actions), 286);
+}
+
+#[test]
+fn day13_shuttle_search() {
+    let notes = day13::load_bus_notes(&example("day13.txt")).unwrap();
+
+    assert_eq!(day13::find_best_bus(notes.departure_timestamp, &notes.buses), Some((59, 5)));
+    assert_eq!(day13::solve_all(&notes.congruences).value, 1068781);
+
+    // the further examples of the second part only list the buses
+    let further_examples = [
+        ("17,x,13,19", 3417),
+        ("67,7,59,61", 754018),
+        ("67,x,7,59,61", 779210),
+        ("67,7,x,59,61", 1261476),
+        ("1789,37,47,1889", 1202161486),
+    ];
+
+    for (buses, timestamp) in further_examples.iter() {
+        let congruences = day13::parse_bus_info_task2(&format!("0\n{}\n", buses)).unwrap();
+        assert_eq!(day13::solve_all(&congruences).value, *timestamp, "buses {}", buses);
+    }
+}
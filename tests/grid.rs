@@ -0,0 +1,61 @@
+use aoc20::grid::Grid;
+
+fn digits() -> Grid<u32> {
+    return Grid::parse("123\n456\n", "expected a digit", |ch| ch.to_digit(10)).unwrap();
+}
+
+#[test]
+fn parses_rows_of_cells() {
+    let grid = digits();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid, Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]));
+    assert_eq!(grid.to_string(), "123\n456");
+}
+
+#[test]
+fn reports_bad_cells_and_ragged_rows() {
+    let error = Grid::parse("123\n4x6\n", "expected a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column, error.message.as_str()), (2, 2, "expected a digit"));
+
+    let error = Grid::parse("123\n45\n", "expected a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.message.as_str()), (2, "row has width 2, expected 3"));
+}
+
+#[test]
+fn indexes_with_and_without_wrapping() {
+    let grid = digits();
+
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get(2, 1), Some(&6));
+    assert_eq!(grid.get(3, 1), None);
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(*grid.get_wrapping(3, 1), 4);
+    assert_eq!(*grid.get_wrapping(-1, -1), 6);
+}
+
+#[test]
+#[should_panic]
+fn index_outside_panics() {
+    let _ = digits()[(0, 2)];
+}
+
+#[test]
+fn iterates_neighbourhoods() {
+    let grid = digits();
+
+    assert_eq!(grid.neighbours(0, 0).copied().collect::<Vec<_>>(), vec![2, 4, 5]);
+    assert_eq!(grid.neighbours(1, 1).copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 6]);
+    assert_eq!(grid.ray(0, 0, (1, 0)).copied().collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(grid.ray(0, 0, (-1, 0)).count(), 0);
+}
+
+#[test]
+fn iterates_rows_and_columns() {
+    let grid = digits();
+
+    assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(grid.columns().map(|column| column.sum::<u32>()).collect::<Vec<_>>(), vec![5, 7, 9]);
+    assert_eq!(grid.map(|(x, y), cell| cell * 10 + (x + y) as u32).to_string(), "102132\n415263");
+}